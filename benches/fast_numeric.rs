@@ -0,0 +1,44 @@
+// Compares `write!` with `#[enum_display(fast_numeric)]` for an integer tuple variant, run with
+// `cargo bench --features fast_numeric`
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use enum_display::EnumDisplay;
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum Default {
+    #[display("{variant}({0}, {1})")]
+    Point(u64, i32),
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(fast_numeric)]
+enum FastNumeric {
+    #[display("{variant}({0}, {1})")]
+    Point(u64, i32),
+}
+
+const ITERATIONS: u64 = 2_000_000;
+
+// Formats every value into a reused buffer, so only the formatting itself is measured
+fn measure<T: std::fmt::Display>(make: impl Fn(u64) -> T) -> Duration {
+    let mut buffer = String::with_capacity(64);
+    let start = Instant::now();
+    for i in 0..ITERATIONS {
+        buffer.clear();
+        write!(buffer, "{}", black_box(make(i))).unwrap();
+        black_box(&buffer);
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let default = measure(|i| Default::Point(i * 7919, -(i as i32)));
+    let fast = measure(|i| FastNumeric::Point(i * 7919, -(i as i32)));
+    let per_call = |elapsed: Duration| elapsed.as_nanos() as f64 / ITERATIONS as f64;
+    println!("default:      {:>6.1} ns/iter", per_call(default));
+    println!("fast_numeric: {:>6.1} ns/iter", per_call(fast));
+}
@@ -0,0 +1,82 @@
+//! The case names and transforms `#[enum_display(case = "...")]` accepts, shared by the derive
+//! and the runtime `enum_display::transform_ident` so the two always agree. Not meant to be used
+//! directly.
+
+use convert_case::{Boundary, Case, Converter};
+
+/// A case transform is either one of `convert_case`'s cases, or one of the pseudo-cases that only
+/// touch the first character and so don't split the name into words.
+#[derive(Clone, Copy)]
+pub enum CaseTransform {
+    Case(Case),
+    FirstUpper,
+    FirstLower,
+}
+
+/// Every `convert_case` case name accepted by the `case` attribute, in the order they're listed
+/// in errors.
+pub const CASE_NAMES: &[(&str, Case)] = &[
+    ("Upper", Case::Upper),
+    ("Lower", Case::Lower),
+    ("Title", Case::Title),
+    ("Toggle", Case::Toggle),
+    ("Camel", Case::Camel),
+    ("Pascal", Case::Pascal),
+    ("UpperCamel", Case::UpperCamel),
+    ("Snake", Case::Snake),
+    ("UpperSnake", Case::UpperSnake),
+    ("ScreamingSnake", Case::ScreamingSnake),
+    ("Kebab", Case::Kebab),
+    ("Cobol", Case::Cobol),
+    ("UpperKebab", Case::UpperKebab),
+    ("Train", Case::Train),
+    ("Flat", Case::Flat),
+    ("UpperFlat", Case::UpperFlat),
+    ("Alternating", Case::Alternating),
+];
+
+/// The pseudo-case names, listed after [`CASE_NAMES`].
+pub const PSEUDO_CASE_NAMES: &[(&str, CaseTransform)] = &[
+    ("FirstUpper", CaseTransform::FirstUpper),
+    ("FirstLower", CaseTransform::FirstLower),
+];
+
+/// The transform a case name stands for, if it's one of the names above.
+pub fn case_transform(case_name: &str) -> Option<CaseTransform> {
+    CASE_NAMES
+        .iter()
+        .map(|(name, case)| (*name, CaseTransform::Case(*case)))
+        .chain(PSEUDO_CASE_NAMES.iter().copied())
+        .find(|(name, _)| *name == case_name)
+        .map(|(_, case)| case)
+}
+
+/// Transforms `ident`, splitting it into words at `boundaries`, or `convert_case`'s defaults
+/// when it's `None`.
+pub fn transform_case(
+    ident: String,
+    case_transform: Option<CaseTransform>,
+    boundaries: Option<&[Boundary]>,
+) -> String {
+    match case_transform {
+        Some(CaseTransform::Case(case)) => {
+            let converter = Converter::new().to_case(case);
+            match boundaries {
+                Some(boundaries) => converter.set_boundaries(boundaries),
+                None => converter,
+            }
+            .convert(ident)
+        }
+        Some(CaseTransform::FirstUpper) => map_first_char(&ident, char::to_uppercase),
+        Some(CaseTransform::FirstLower) => map_first_char(&ident, char::to_lowercase),
+        None => ident,
+    }
+}
+
+fn map_first_char<I: Iterator<Item = char>>(s: &str, f: impl FnOnce(char) -> I) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => f(first).chain(chars).collect(),
+        None => String::new(),
+    }
+}
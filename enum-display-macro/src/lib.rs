@@ -1,103 +1,3742 @@
-use convert_case::{Case, Casing};
+use convert_case::Boundary;
+use enum_display_case::{
+    case_transform, transform_case, CaseTransform, CASE_NAMES, PSEUDO_CASE_NAMES,
+};
 use proc_macro::{self, TokenStream};
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
-
-fn parse_case_name(case_name: &str) -> Case {
-    match case_name {
-        "Upper" => Case::Upper,
-        "Lower" => Case::Lower,
-        "Title" => Case::Title,
-        "Toggle" => Case::Toggle,
-        "Camel" => Case::Camel,
-        "Pascal" => Case::Pascal,
-        "UpperCamel" => Case::UpperCamel,
-        "Snake" => Case::Snake,
-        "UpperSnake" => Case::UpperSnake,
-        "ScreamingSnake" => Case::ScreamingSnake,
-        "Kebab" => Case::Kebab,
-        "Cobol" => Case::Cobol,
-        "UpperKebab" => Case::UpperKebab,
-        "Train" => Case::Train,
-        "Flat" => Case::Flat,
-        "UpperFlat" => Case::UpperFlat,
-        "Alternating" => Case::Alternating,
-        _ => panic!("Unrecognized case name: {}", case_name),
-    }
-}
-
-#[proc_macro_derive(EnumDisplay, attributes(enum_display))]
-pub fn derive(input: TokenStream) -> TokenStream {
-    // Parse the input tokens into a syntax tree
-    let DeriveInput {
-        ident, data, attrs, ..
-    } = parse_macro_input!(input);
-
-    // Should we transform the case of the enum variants?
-    let mut case_transform: Option<Case> = None;
-
-    // Find the enum_display attribute
-    for attr in attrs.into_iter() {
-        if attr.path.is_ident("enum_display") {
-            let meta = attr.parse_meta().unwrap();
-            if let syn::Meta::List(list) = meta {
-                for nested in list.nested {
-                    if let syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) = nested {
-                        if name_value.path.is_ident("case") {
-                            if let syn::Lit::Str(lit_str) = name_value.lit {
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use regex::Regex;
+use std::sync::OnceLock;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, DeriveInput, FieldsNamed, FieldsUnnamed, Ident, Token, Variant,
+};
+
+// Identifiers introduced by the generated code follow a fixed scheme so expansions stay stable,
+// see the snapshots in tests/expand: tuple fields are bound as `_unnamed_N`, and every other
+// binding the code needs is prefixed with `__enum_display_`.
+
+// Compile a regex once per compiler process instead of once for every format string
+macro_rules! regex {
+    ($re:literal) => {{
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        REGEX.get_or_init(|| Regex::new($re).unwrap())
+    }};
+}
+
+fn parse_case_name(case_name: &str, span: Span) -> syn::Result<CaseTransform> {
+    case_transform(case_name).ok_or_else(|| {
+        let valid_names: Vec<&str> = CASE_NAMES
+            .iter()
+            .map(|(name, _)| *name)
+            .chain(PSEUDO_CASE_NAMES.iter().map(|(name, _)| *name))
+            .collect();
+        syn::Error::new(
+            span,
+            format!(
+                "unrecognized case name `{}`, expected one of: {}",
+                case_name,
+                valid_names.join(", ")
+            ),
+        )
+    })
+}
+
+// Case names can be given as a string, `case = "Snake"`, or as a path, `case = Snake` or
+// `case = Case::Snake`, in which case only the last segment is considered
+fn parse_case_lit(lit_str: &syn::LitStr) -> syn::Result<CaseTransform> {
+    parse_case_name(&lit_str.value(), lit_str.span())
+}
+
+fn parse_case_path(path: &syn::Path) -> syn::Result<CaseTransform> {
+    match path.segments.last() {
+        Some(segment) => parse_case_name(&segment.ident.to_string(), segment.ident.span()),
+        None => parse_case_name("", path.span()),
+    }
+}
+
+// A single argument of #[enum_display(...)] or #[display(...)]. `syn::NestedMeta` only allows
+// a literal after `=`, so `key = Path` is parsed separately.
+enum AttrArg {
+    Nested(syn::NestedMeta),
+    NamePath(syn::Path, syn::Path),
+}
+
+impl Parse for AttrArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        if fork.call(syn::Path::parse_mod_style).is_ok()
+            && fork.peek(Token![=])
+            && !fork.peek2(syn::Lit)
+        {
+            let key = input.call(syn::Path::parse_mod_style)?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse()?;
+            return Ok(Self::NamePath(key, value));
+        }
+        input.parse().map(Self::Nested)
+    }
+}
+
+fn parse_attr_args(attr: &Attribute) -> syn::Result<Punctuated<AttrArg, Token![,]>> {
+    attr.parse_args_with(Punctuated::parse_terminated)
+}
+
+// Names that don't have the prefix or suffix are left as they are, and so are names that would
+// be left empty
+fn strip_affixes(name: String, prefix: &str, suffix: &str) -> String {
+    let stripped = name.strip_prefix(prefix).unwrap_or(&name);
+    let stripped = stripped.strip_suffix(suffix).unwrap_or(stripped);
+    if stripped.is_empty() {
+        name
+    } else {
+        stripped.to_string()
+    }
+}
+
+// Rewrite positional placeholders like `{0}`, `{1:>4}` or `{ 2 :#x}` to the identifiers the
+// fields are bound to, in declaration order, so they can be captured by `write!`. The index has
+// to be followed by the end of the placeholder or the `:` starting its spec, which is kept as
+// is. Escaped braces are matched first so `{{0}}` stays a literal `{0}`.
+fn translate_numeric_placeholders(fmt: &str, fields: &[Ident]) -> String {
+    let re = regex!(r"\{\{|\}\}|\{\s*(\d+|[A-Za-z_][A-Za-z0-9_]*)?\s*(:[^}]*)?\}");
+    re.replace_all(fmt, |caps: &regex::Captures| {
+        let arg = match caps.get(1) {
+            Some(arg) => arg.as_str(),
+            None => return caps[0].to_string(),
+        };
+        let field = arg
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| fields.get(index));
+        let arg = match field {
+            Some(field) => field.unraw().to_string(),
+            None => arg.to_string(),
+        };
+        let spec = caps.get(2).map_or("", |spec| spec.as_str());
+        format!("{{{}{}}}", arg, translate_count_references(spec, fields))
+    })
+    .to_string()
+}
+
+// Rewrite the positional widths and precisions in a spec like `:>1$.2$` to the identifiers the
+// fields are bound to. A `0` before more digits is the zero padding flag, so `05$` is a width
+// from field 5 padded with zeros.
+fn translate_count_references(spec: &str, fields: &[Ident]) -> String {
+    let re = regex!(r"(0?)(\d+)\$");
+    re.replace_all(spec, |caps: &regex::Captures| {
+        let field = caps[2]
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| fields.get(index));
+        match field {
+            Some(field) => format!("{}{}$", &caps[1], field.unraw()),
+            None => caps[0].to_string(),
+        }
+    })
+    .to_string()
+}
+
+// Catch unbalanced braces and malformed placeholders up front, `write!` would otherwise report
+// them from inside the expansion
+fn check_format_syntax(fmt: &str) -> Result<(), String> {
+    let argument = regex!(r"^\s*(\d+|[A-Za-z_][A-Za-z0-9_]*)?\s*$");
+    let mut chars = fmt.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if chars.next_if(|(_, next)| *next == '{').is_some() => {}
+            '{' => {
+                let end = loop {
+                    match chars.next() {
+                        Some((end, '}')) => break end,
+                        Some((_, '{')) | None => {
+                            return Err(
+                                "unclosed `{` in format string, use `{{` for a literal brace"
+                                    .to_string(),
+                            )
+                        }
+                        Some(_) => {}
+                    }
+                };
+                let placeholder = &fmt[start + 1..end];
+                let arg = placeholder.split(':').next().unwrap_or_default();
+                if !argument.is_match(arg) {
+                    return Err(format!(
+                        "invalid placeholder `{{{}}}` in format string, expected a field name or position",
+                        placeholder
+                    ));
+                }
+            }
+            '}' if chars.next_if(|(_, next)| *next == '}').is_some() => {}
+            '}' => {
+                return Err(
+                    "unmatched `}` in format string, use `}}` for a literal brace".to_string(),
+                )
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// The names a format string refers to, either as an argument like `{name:>4}` or as a width or
+// precision like `{0:>width$}`. Positional arguments are left out.
+fn named_placeholders(fmt: &str) -> Vec<String> {
+    let count = regex!(r"([A-Za-z_][A-Za-z0-9_]*)\$");
+    let mut names = Vec::new();
+    for (arg, spec) in placeholders(fmt) {
+        if !arg.is_empty() && !arg.starts_with(|c: char| c.is_ascii_digit()) {
+            names.push(arg);
+        }
+        names.extend(count.captures_iter(&spec).map(|caps| caps[1].to_string()));
+    }
+    names
+}
+
+// Every placeholder in a format string as its argument and spec, `{ 0 :>4}` is `("0", ">4")`
+fn placeholders(fmt: &str) -> Vec<(String, String)> {
+    let placeholder = regex!(r"\{\{|\}\}|\{\s*([^{}:]*?)\s*(?::([^{}]*))?\}");
+    placeholder
+        .captures_iter(fmt)
+        .filter_map(|caps| {
+            let arg = caps.get(1)?.as_str().to_string();
+            let spec = caps.get(2).map_or("", |spec| spec.as_str()).to_string();
+            Some((arg, spec))
+        })
+        .collect()
+}
+
+// A piece of a format string, literal text with its braces unescaped or a placeholder's
+// argument and spec
+enum FormatPiece {
+    Text(String),
+    Placeholder(String, String),
+}
+
+fn format_pieces(fmt: &str) -> Vec<FormatPiece> {
+    let placeholder = regex!(r"\{\{|\}\}|\{\s*([^{}:]*?)\s*(?::([^{}]*))?\}");
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut end = 0;
+    for caps in placeholder.captures_iter(fmt) {
+        let whole = caps.get(0).unwrap();
+        text.push_str(&fmt[end..whole.start()]);
+        end = whole.end();
+        match caps.get(1) {
+            Some(arg) => {
+                if !text.is_empty() {
+                    pieces.push(FormatPiece::Text(std::mem::take(&mut text)));
+                }
+                let spec = caps.get(2).map_or("", |spec| spec.as_str());
+                pieces.push(FormatPiece::Placeholder(
+                    arg.as_str().to_string(),
+                    spec.to_string(),
+                ));
+            }
+            None => text.push_str(&whole.as_str()[..1]),
+        }
+    }
+    text.push_str(&fmt[end..]);
+    if !text.is_empty() {
+        pieces.push(FormatPiece::Text(text));
+    }
+    pieces
+}
+
+// The `__private` function `fast_numeric` writes a field of this type with, if it's a number
+fn numeric_writer(ty: &syn::Type) -> Option<Ident> {
+    let name = match ty {
+        syn::Type::Path(path) if path.qself.is_none() => path.path.get_ident()?.to_string(),
+        _ => return None,
+    };
+    // Floats are left to `Display`, no faster writer gives the same output
+    INTEGER_TYPES
+        .contains(&name.as_str())
+        .then(|| format_ident!("write_integer"))
+}
+
+// The formatting trait a placeholder's spec asks for, `{:?}` needs `Debug`, `{:x}` `LowerHex`
+fn format_trait(spec: &str) -> Ident {
+    let name = if spec.ends_with('?') {
+        "Debug"
+    } else {
+        match spec.chars().last() {
+            Some('x') => "LowerHex",
+            Some('X') => "UpperHex",
+            Some('o') => "Octal",
+            Some('b') => "Binary",
+            Some('e') => "LowerExp",
+            Some('E') => "UpperExp",
+            _ => "Display",
+        }
+    };
+    Ident::new(name, Span::call_site())
+}
+
+// Whether a type mentions any of the given type parameters, anywhere in its tokens
+fn mentions_params(tokens: TokenStream2, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_params(group.stream(), params),
+        _ => false,
+    })
+}
+
+// The formatting traits are implemented for references to anything that implements them, so a
+// reference is bounded through what it points to. Bounding `&'static T` itself would make every
+// other `&T` the impl formats need that lifetime too.
+fn strip_references(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Reference(reference) => strip_references(&reference.elem),
+        syn::Type::Paren(paren) => strip_references(&paren.elem),
+        syn::Type::Group(group) => strip_references(&group.elem),
+        ty => ty,
+    }
+}
+
+// Rewrite `{enum}` and `{variant}` placeholders, with or without a spec, to the identifiers the
+// names are bound to. `enum` is a keyword so it can't be captured by `write!` directly, and
+// binding `variant` as is would shadow a field of the same name.
+fn translate_name_placeholders(fmt: &str) -> String {
+    let re = regex!(r"\{\{|\}\}|\{(enum|variant)([:}])");
+    re.replace_all(fmt, |caps: &regex::Captures| match caps.get(1) {
+        Some(name) => format!("{{__enum_display_{}{}", name.as_str(), &caps[2]),
+        None => caps[0].to_string(),
+    })
+    .to_string()
+}
+
+// `write_str` is the cheapest way to write a plain name, `pad` also applies the formatter's
+// width, alignment and fill
+fn write_name(pad: bool) -> TokenStream2 {
+    if pad {
+        quote! { ::core::fmt::Formatter::pad }
+    } else {
+        quote! { ::core::fmt::Formatter::write_str }
+    }
+}
+
+// The formatter the generated code writes to. Its span keeps it apart from a field called `f`,
+// which the match arms bind under the field's own name.
+fn formatter() -> Ident {
+    Ident::new("f", Span::mixed_site())
+}
+
+// The `fmt::Write` that `display_into` writes to, kept apart from fields the same way
+fn buffer() -> Ident {
+    Ident::new("buf", Span::mixed_site())
+}
+
+// Escape braces so a literal string can be spliced into a format string
+fn escape_braces(s: &str) -> String {
+    s.replace('{', "{{").replace('}', "}}")
+}
+
+// Escape a string the way `serde_json` writes it, non-ASCII characters are left as they are
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            c if c < ' ' => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Attributes on the enum itself, e.g. #[enum_display(case = "Kebab")]
+struct EnumAttrs {
+    case_transform: Option<CaseTransform>,
+    // The case of `{variant}` in format strings, when it should differ from `case`
+    variant_case: Option<CaseTransform>,
+    prefix: String,
+    suffix: String,
+    // The format string used by variants without their own #[display("...")]
+    format: Option<syn::LitStr>,
+    // Mark `fmt` as #[inline] even when some variants have format strings
+    inline: bool,
+    // Write plain names with `Formatter::pad` so width, alignment and fill are respected
+    pad: bool,
+    // Display variants by their #[serde(rename = "...")] when they have one
+    use_serde_rename: bool,
+    // Strings shared by every variant in a group, from #[enum_display(group(errors = "..."))]
+    groups: Vec<(Ident, syn::LitStr)>,
+    // Where the generated code finds this crate's items, for facades that re-export it
+    crate_path: syn::Path,
+    // Also implement Debug, writing the same output as Display
+    debug: bool,
+    // Wrap every variant's output in escaped double quotes
+    quote: bool,
+    // Quote every variant's output as a JSON string, escaping control characters too
+    json: bool,
+    // The integer type from #[repr(...)], which `{discriminant}` is bound as
+    repr: Option<Ident>,
+    // Leave the generated functions out of coverage reports on nightly
+    no_coverage: bool,
+    // The case named fields can also be referred to by in format strings, with where it was set
+    rename_all_fields: Option<(CaseTransform, Span)>,
+    // Write variant names as `Enum::Variant`
+    qualified: bool,
+    // Removed from variant names that have them, before the case transform
+    strip_prefix: String,
+    strip_suffix: String,
+    // Write variants with fields like derived Debug, unless they have a format of their own
+    debug_fields: bool,
+    // Reject any text the expansion writes as-is that isn't ASCII
+    ascii_only: bool,
+    // Strip the common indentation from every format string, like `indoc!`
+    dedent: bool,
+    // Where names are split into words for the case transform, e.g. `boundaries = "aA:1A"`
+    boundaries: Option<Vec<Boundary>>,
+    // Let `EnumFromStr` and `EnumTryFrom` accept the names in any case
+    parse_case_insensitive: bool,
+    // Reject variants that display as the same fixed string
+    unique: bool,
+    // Write variants like `debug_fields` does when the formatter's alternate flag is set
+    debug_alternate: bool,
+    // Write integer fields with `itoa` instead of `write!` where nothing else is needed
+    fast_numeric: bool,
+    // The trait bare field placeholders are written with, unless a variant says otherwise
+    field_repr: FieldRepr,
+    // Reject repeated arguments and `#[display]` on fields instead of ignoring them
+    strict: bool,
+}
+
+impl EnumAttrs {
+    fn from_attrs(attrs: Vec<Attribute>) -> syn::Result<Self> {
+        // Should we transform the case of the enum variants?
+        let mut case_transform: Option<CaseTransform> = None;
+        let mut variant_case: Option<CaseTransform> = None;
+        // Text wrapped around every variant's output
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut format: Option<syn::LitStr> = None;
+        let mut inline = false;
+        let mut pad = false;
+        let mut use_serde_rename = false;
+        let mut groups: Vec<(Ident, syn::LitStr)> = Vec::new();
+        let mut crate_path: syn::Path = syn::parse_quote!(::enum_display);
+        let mut debug = false;
+        let mut quote = false;
+        let mut json = false;
+        let mut repr: Option<Ident> = None;
+        let mut no_coverage = false;
+        let mut rename_all_fields: Option<(CaseTransform, Span)> = None;
+        let mut qualified = false;
+        let mut strip_prefix = String::new();
+        let mut strip_suffix = String::new();
+        let mut debug_fields = false;
+        let mut ascii_only = false;
+        let mut dedent = false;
+        let mut boundaries: Option<Vec<Boundary>> = None;
+        let mut parse_case_insensitive = false;
+        let mut unique = false;
+        let mut debug_alternate = false;
+        let mut fast_numeric = false;
+        let mut field_repr = FieldRepr::Display;
+        let mut strict = false;
+        // Every named argument in the order given, checked for repeats by `strict`
+        let mut arg_names: Vec<syn::Path> = Vec::new();
+        // The literals written into the output as they are, checked by `ascii_only`
+        let mut literals: Vec<syn::LitStr> = Vec::new();
+
+        // Find the enum_display attribute
+        for attr in attrs.into_iter() {
+            if attr.path.is_ident("repr") {
+                repr = parse_repr(&attr).or(repr);
+            }
+            if attr.path.is_ident("enum_display") {
+                for arg in parse_attr_args(&attr)? {
+                    arg_names.extend(arg_name(&arg).cloned());
+                    match arg {
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(
+                            name_value,
+                        ))) if ENUM_STRING_ARGS
+                            .iter()
+                            .any(|name| name_value.path.is_ident(name)) =>
+                        {
+                            let path = name_value.path;
+                            let lit_str = expect_lit_str(name_value.lit)?;
+                            if path.is_ident("case") {
                                 // Set the case transform
-                                case_transform = Some(parse_case_name(lit_str.value().as_str()));
+                                case_transform = Some(parse_case_lit(&lit_str)?);
+                            } else if path.is_ident("variant_case") {
+                                variant_case = Some(parse_case_lit(&lit_str)?);
+                            } else if path.is_ident("rename_all_fields") {
+                                rename_all_fields =
+                                    Some((parse_case_lit(&lit_str)?, lit_str.span()));
+                            } else if path.is_ident("prefix") {
+                                prefix = lit_str.value();
+                                literals.push(lit_str);
+                            } else if path.is_ident("suffix") {
+                                suffix = lit_str.value();
+                                literals.push(lit_str);
+                            } else if path.is_ident("boundaries") {
+                                boundaries = Some(parse_boundaries(&lit_str)?);
+                            } else if path.is_ident("strip_prefix") {
+                                strip_prefix = lit_str.value();
+                            } else if path.is_ident("strip_suffix") {
+                                strip_suffix = lit_str.value();
+                            } else if path.is_ident("format") {
+                                literals.push(lit_str.clone());
+                                format = Some(lit_str);
+                            } else if path.is_ident("crate") {
+                                crate_path = lit_str.parse()?;
+                            } else if path.is_ident("field_repr") {
+                                field_repr = parse_field_repr(&lit_str)?;
                             }
                         }
+                        AttrArg::NamePath(key, value) if key.is_ident("case") => {
+                            case_transform = Some(parse_case_path(&value)?);
+                        }
+                        AttrArg::NamePath(key, value) if key.is_ident("variant_case") => {
+                            variant_case = Some(parse_case_path(&value)?);
+                        }
+                        AttrArg::NamePath(key, value) if key.is_ident("rename_all_fields") => {
+                            rename_all_fields = Some((parse_case_path(&value)?, value.span()));
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("inline") =>
+                        {
+                            inline = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("pad") =>
+                        {
+                            pad = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("use_serde_rename") =>
+                        {
+                            use_serde_rename = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("debug") =>
+                        {
+                            debug = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("no_coverage") =>
+                        {
+                            no_coverage = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("qualified") =>
+                        {
+                            qualified = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("debug_fields") =>
+                        {
+                            debug_fields = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("ascii_only") =>
+                        {
+                            ascii_only = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("dedent") =>
+                        {
+                            dedent = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("parse_case_insensitive") =>
+                        {
+                            parse_case_insensitive = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("unique") =>
+                        {
+                            unique = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("debug_alternate") =>
+                        {
+                            debug_alternate = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("fast_numeric") =>
+                        {
+                            fast_numeric = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("strict") =>
+                        {
+                            strict = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("quote") =>
+                        {
+                            quote = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                            if path.is_ident("json") =>
+                        {
+                            json = true;
+                        }
+                        AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                            if list.path.is_ident("group") =>
+                        {
+                            groups.extend(parse_groups(&list)?);
+                        }
+                        arg => return Err(unknown_enum_display_arg(arg)),
+                    }
+                }
+            }
+        }
+
+        if strict {
+            check_repeated_args(&arg_names, &["group"])?;
+        }
+
+        if dedent {
+            format = format.map(|format| dedent_lit(&format));
+        }
+
+        if ascii_only {
+            for lit_str in literals
+                .iter()
+                .chain(groups.iter().map(|(_, lit_str)| lit_str))
+            {
+                ensure_ascii(&lit_str.value(), lit_str.span())?;
+            }
+        }
+
+        // Enums without their own case fall back to the build environment's default. Cargo only
+        // sets `CARGO_PRIMARY_PACKAGE` for the packages it was asked to build, so dependencies
+        // keep the names they were published with
+        let primary_package = std::env::var_os("CARGO_PRIMARY_PACKAGE").is_some();
+        if case_transform.is_none() && primary_package {
+            if let Ok(case_name) = std::env::var("ENUM_DISPLAY_DEFAULT_CASE") {
+                case_transform = Some(parse_case_name(&case_name, Span::call_site()).map_err(
+                    |err| {
+                        syn::Error::new(
+                            err.span(),
+                            format!("in `ENUM_DISPLAY_DEFAULT_CASE`: {}", err),
+                        )
+                    },
+                )?);
+            }
+        }
+
+        Ok(Self {
+            case_transform,
+            variant_case,
+            prefix,
+            suffix,
+            format,
+            inline,
+            pad,
+            use_serde_rename,
+            groups,
+            crate_path,
+            debug,
+            quote,
+            json,
+            repr,
+            no_coverage,
+            rename_all_fields,
+            qualified,
+            strip_prefix,
+            strip_suffix,
+            debug_fields,
+            ascii_only,
+            dedent,
+            boundaries,
+            parse_case_insensitive,
+            unique,
+            debug_alternate,
+            fast_numeric,
+            field_repr,
+            strict,
+        })
+    }
+}
+
+// Boundaries are given by example, like `convert_case`'s `Boundary::list_from`: `aA` splits
+// between a lower and an upper case letter, `AAa` before the last capital of an acronym, `a1`
+// and `1a` around digits, with `:` separating examples. An empty string never splits.
+fn parse_boundaries(lit_str: &syn::LitStr) -> syn::Result<Vec<Boundary>> {
+    let examples = lit_str.value();
+    let boundaries = Boundary::list_from(&examples);
+    if boundaries.is_empty() && !examples.is_empty() {
+        return Err(syn::Error::new(
+            lit_str.span(),
+            format!(
+                "no word boundaries in `{}`, they're given by example like `aA`, `AAa`, `a1` or `_`",
+                examples
+            ),
+        ));
+    }
+    Ok(boundaries)
+}
+
+// Removes the indentation shared by every line of a multiline template, which lets it be
+// indented along with the code around it. Like `indoc!`, a leading newline is dropped, and lines
+// of only whitespace don't count towards the shared indentation.
+fn dedent(text: &str) -> String {
+    // Without a leading newline the first line starts right after the quote, so its
+    // indentation isn't comparable to the other lines
+    let (text, skip) = match text
+        .strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+    {
+        Some(text) => (text, 0),
+        None => (text, 1),
+    };
+    let indent = text
+        .lines()
+        .skip(skip)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+    let mut dedented = String::with_capacity(text.len());
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        if i < skip {
+            dedented.push_str(line);
+        } else {
+            let strip = line
+                .bytes()
+                .take(indent)
+                .take_while(|&byte| byte == b' ' || byte == b'\t')
+                .count();
+            dedented.push_str(&line[strip..]);
+        }
+    }
+    dedented
+}
+
+fn dedent_lit(lit_str: &syn::LitStr) -> syn::LitStr {
+    syn::LitStr::new(&dedent(&lit_str.value()), lit_str.span())
+}
+
+// Only the text known at expansion can be checked, fields are formatted at runtime
+fn ensure_ascii(text: &str, span: Span) -> syn::Result<()> {
+    match text.chars().find(|c| !c.is_ascii()) {
+        Some(c) => Err(syn::Error::new(
+            span,
+            format!("`{}` isn't ASCII, which `ascii_only` requires", c),
+        )),
+        None => Ok(()),
+    }
+}
+
+// The integer type named by #[repr(...)], ignoring layout hints like `C`
+fn parse_repr(attr: &Attribute) -> Option<Ident> {
+    match attr.parse_meta().ok()? {
+        syn::Meta::List(list) => list.nested.into_iter().find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => path
+                .get_ident()
+                .filter(|ident| INTEGER_TYPES.contains(&ident.to_string().as_str()))
+                .cloned(),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+// Forces a variant's whole output into one case, #[display(uppercase)] or #[display(lowercase)]
+#[derive(Clone, Copy)]
+enum LetterCase {
+    Upper,
+    Lower,
+}
+
+// The trait bare field placeholders like `{0}` are written with, #[enum_display(field_repr = "debug")]
+#[derive(Clone, Copy, PartialEq)]
+enum FieldRepr {
+    Display,
+    Debug,
+}
+
+fn parse_field_repr(lit_str: &syn::LitStr) -> syn::Result<FieldRepr> {
+    match lit_str.value().as_str() {
+        "display" => Ok(FieldRepr::Display),
+        "debug" => Ok(FieldRepr::Debug),
+        other => Err(syn::Error::new(
+            lit_str.span(),
+            format!(
+                "unknown field_repr `{}`, expected `display` or `debug`",
+                other
+            ),
+        )),
+    }
+}
+
+// Attributes on a single variant, e.g. #[display("{variant}: {0}")] or #[display(case = "Snake")]
+struct VariantAttrs {
+    format: Option<syn::LitStr>,
+    case_transform: Option<CaseTransform>,
+    // Where #[display(transparent)] was written, so misuse can point at it
+    transparent: Option<Span>,
+    // Names for tuple fields from #[display(fields(lat, lon), "...")]
+    field_aliases: Option<(Vec<Ident>, Span)>,
+    // Extra values computed for the format string from #[display(bind(sum = "x + y"), "...")]
+    bindings: Vec<(Ident, syn::Expr)>,
+    // The enum level group this variant shares its output with, #[display(group = "errors")]
+    group: Option<syn::LitStr>,
+    // Wrap the output in escaped double quotes, #[display(quote)]
+    quote: bool,
+    // Write every tuple field separated by this, #[display(join = ", ")]
+    join: Option<syn::LitStr>,
+    // A function that writes the variant instead, #[display(with = "fmt_point")]
+    with: Option<syn::Path>,
+    // Names for named fields in the enum's `rename_all_fields` case, paired with the field
+    field_renames: Vec<(Ident, Ident)>,
+    // Applied to everything else the variant writes, after its format string
+    letter_case: Option<LetterCase>,
+    // Where #[display(default)] was written, the variant `from_str_or_default` falls back to
+    default: Option<Span>,
+    // Write the fields like derived Debug, #[display(debug_fields)]
+    debug_fields: Option<Span>,
+    // Write every named field as `name: value` with Display, separated by this,
+    // #[display(all_fields)] or #[display(all_fields = "; ")]
+    all_fields: Option<syn::LitStr>,
+    // Constants in scope the format string can refer to, #[display(extern(VERSION), "...")]
+    externs: Vec<Ident>,
+    // The type every tuple field is converted to with `as` before formatting, #[display(cast = "u64")]
+    cast: Option<syn::Type>,
+    // Where #[display(trim)] was written, which drops the whitespace around the formatted output
+    trim: Option<Span>,
+    // The most characters written before the output is cut off with `…`, #[display(truncate = 20)]
+    truncate: Option<(usize, Span)>,
+    // Overrides the enum's `field_repr` for this variant, #[display(field_repr = "display")]
+    field_repr: Option<FieldRepr>,
+    // The fields written with their digits grouped by commas, #[display(group_digits(0, total))],
+    // by position or name as given
+    group_digits: Option<(Vec<syn::NestedMeta>, Span)>,
+    // The positions of the fields in `group_digits`, once resolved against the variant's fields
+    grouped_fields: Vec<usize>,
+}
+
+// Lists of names can be given as idents or strings, `fields(lat, lon)` or `fields("lat", "lon")`
+fn parse_names(list: &syn::MetaList, expected: &str) -> syn::Result<Vec<Ident>> {
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            syn::NestedMeta::Lit(syn::Lit::Str(lit_str)) => lit_str.parse(),
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => path
+                .get_ident()
+                .cloned()
+                .ok_or_else(|| syn::Error::new_spanned(path, expected)),
+            _ => Err(syn::Error::new_spanned(nested, expected)),
+        })
+        .collect()
+}
+
+// Bindings are written as `bind(name = "expression", ...)`
+fn parse_bindings(list: &syn::MetaList) -> syn::Result<Vec<(Ident, syn::Expr)>> {
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) => {
+                let name = path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| syn::Error::new_spanned(path, "expected a binding name"))?;
+                Ok((name, lit_str.parse()?))
+            }
+            _ => Err(syn::Error::new_spanned(
+                nested,
+                "expected a binding like `name = \"expression\"`",
+            )),
+        })
+        .collect()
+}
+
+// Groups are written as `group(name = "shared output", ...)`
+fn parse_groups(list: &syn::MetaList) -> syn::Result<Vec<(Ident, syn::LitStr)>> {
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) => {
+                let name = path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| syn::Error::new_spanned(path, "expected a group name"))?;
+                Ok((name, lit_str.clone()))
+            }
+            _ => Err(syn::Error::new_spanned(
+                nested,
+                "expected a group like `name = \"output\"`",
+            )),
+        })
+        .collect()
+}
+
+fn expect_lit_str(lit: syn::Lit) -> syn::Result<syn::LitStr> {
+    match lit {
+        syn::Lit::Str(lit_str) => Ok(lit_str),
+        lit => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+    }
+}
+
+// Every argument #[display(...)] accepts besides a bare format string, listed in errors
+const DISPLAY_ARGS: &[&str] = &[
+    "format",
+    "as",
+    "case",
+    "group",
+    "join",
+    "with",
+    "transparent",
+    "quote",
+    "default",
+    "debug_fields",
+    "all_fields",
+    "dedent",
+    "uppercase",
+    "lowercase",
+    "fields",
+    "bind",
+    "extern",
+    "cast",
+    "trim",
+    "truncate",
+    "field_repr",
+    "group_digits",
+];
+
+// The #[enum_display(...)] arguments that take a string, `key = "..."`
+const ENUM_STRING_ARGS: &[&str] = &[
+    "case",
+    "variant_case",
+    "rename_all_fields",
+    "prefix",
+    "suffix",
+    "strip_prefix",
+    "strip_suffix",
+    "boundaries",
+    "format",
+    "crate",
+    "field_repr",
+];
+
+// Every argument #[enum_display(...)] accepts, listed in errors
+const ENUM_DISPLAY_ARGS: &[&str] = &[
+    "case",
+    "variant_case",
+    "rename_all_fields",
+    "prefix",
+    "suffix",
+    "strip_prefix",
+    "strip_suffix",
+    "boundaries",
+    "format",
+    "crate",
+    "field_repr",
+    "group",
+    "inline",
+    "pad",
+    "use_serde_rename",
+    "debug",
+    "no_coverage",
+    "qualified",
+    "debug_fields",
+    "debug_alternate",
+    "ascii_only",
+    "dedent",
+    "parse_case_insensitive",
+    "unique",
+    "quote",
+    "json",
+    "fast_numeric",
+    "strict",
+];
+
+// Misspelled arguments like `fmt = "..."` would otherwise be ignored and silently fall back to
+// the variant's name
+fn unknown_display_arg(arg: AttrArg, attr: &str) -> syn::Error {
+    match arg {
+        AttrArg::Nested(syn::NestedMeta::Lit(lit)) => {
+            syn::Error::new_spanned(lit, "expected a format string")
+        }
+        arg => unknown_arg(arg, attr, "a format string or one of", DISPLAY_ARGS),
+    }
+}
+
+// The same goes for the enum's arguments, which otherwise would be a silent no-op
+fn unknown_enum_display_arg(arg: AttrArg) -> syn::Error {
+    match arg {
+        AttrArg::Nested(syn::NestedMeta::Lit(lit)) => syn::Error::new_spanned(
+            lit,
+            format!(
+                "`enum_display` arguments need a name, expected one of: {}",
+                ENUM_DISPLAY_ARGS.join(", ")
+            ),
+        ),
+        arg => unknown_arg(arg, "enum_display", "one of", ENUM_DISPLAY_ARGS),
+    }
+}
+
+fn unknown_arg(arg: AttrArg, attr: &str, expected: &str, args: &[&str]) -> syn::Error {
+    let (path, tokens) = match arg {
+        AttrArg::NamePath(key, value) => (key.clone(), quote! { #key = #value }),
+        AttrArg::Nested(syn::NestedMeta::Meta(meta)) => (meta.path().clone(), quote! { #meta }),
+        AttrArg::Nested(syn::NestedMeta::Lit(lit)) => {
+            return syn::Error::new_spanned(lit, "unexpected literal");
+        }
+    };
+    let name = quote! { #path }.to_string().replace(' ', "");
+    syn::Error::new_spanned(
+        tokens,
+        format!(
+            "unknown `{}` argument `{}`, expected {}: {}",
+            attr,
+            name,
+            expected,
+            args.join(", ")
+        ),
+    )
+}
+
+// The name an argument is given by, `case` for `case = "Snake"`, nothing for a bare literal
+fn arg_name(arg: &AttrArg) -> Option<&syn::Path> {
+    match arg {
+        AttrArg::NamePath(key, _) => Some(key),
+        AttrArg::Nested(syn::NestedMeta::Meta(meta)) => Some(meta.path()),
+        AttrArg::Nested(syn::NestedMeta::Lit(_)) => None,
+    }
+}
+
+// With `strict`, an argument given twice is an error instead of the last one silently winning.
+// Lists that add to each other, like `bind(...)`, can still be repeated.
+fn check_repeated_args(names: &[syn::Path], repeatable: &[&str]) -> syn::Result<()> {
+    let keys: Vec<String> = names
+        .iter()
+        .map(|name| quote! { #name }.to_string().replace(' ', ""))
+        .collect();
+    let mut errors: Option<syn::Error> = None;
+    for (i, (name, key)) in names.iter().zip(&keys).enumerate() {
+        if repeatable.contains(&key.as_str()) || !keys[..i].contains(key) {
+            continue;
+        }
+        let error = syn::Error::new_spanned(name, format!("`{}` is given more than once", key));
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+// A bare path like #[display(Red)] or #[display(Color::Red)] is shorthand for that output. Every
+// argument is lower case, so a lower case ident is still taken as a misspelled one.
+fn is_symbolic_output(path: &syn::Path) -> bool {
+    let plain = path.leading_colon.is_none()
+        && path
+            .segments
+            .iter()
+            .all(|segment| segment.arguments.is_empty());
+    let uppercase = path.segments.first().is_some_and(|segment| {
+        segment
+            .ident
+            .unraw()
+            .to_string()
+            .starts_with(|c: char| c.is_uppercase())
+    });
+    plain && (uppercase || path.segments.len() > 1)
+}
+
+// A variant can only have one format string, a second one is an error rather than silently
+// replacing the first
+fn set_format(format: &mut Option<syn::LitStr>, lit_str: syn::LitStr) -> syn::Result<()> {
+    if format.is_some() {
+        return Err(syn::Error::new(
+            lit_str.span(),
+            "a format string was already given for this variant",
+        ));
+    }
+    *format = Some(lit_str);
+    Ok(())
+}
+
+// The serialized name from #[serde(rename = "...")] or #[serde(rename(serialize = "..."))].
+// These attributes belong to serde, so anything that doesn't parse is left for serde to report.
+fn parse_serde_rename(attr: &Attribute) -> Option<syn::LitStr> {
+    parse_attr_args(attr)
+        .ok()?
+        .into_iter()
+        .find_map(|arg| match arg {
+            AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }))) if path.is_ident("rename") => Some(lit_str),
+            AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                if list.path.is_ident("rename") =>
+            {
+                list.nested.into_iter().find_map(|nested| match nested {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("serialize") => Some(lit_str),
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+}
+
+impl VariantAttrs {
+    fn from_attrs(
+        attrs: Vec<Attribute>,
+        use_serde_rename: bool,
+        enum_dedent: bool,
+        strict: bool,
+    ) -> syn::Result<Self> {
+        let mut format: Option<syn::LitStr> = None;
+        let mut serde_rename: Option<syn::LitStr> = None;
+        let mut case_transform: Option<CaseTransform> = None;
+        let mut transparent: Option<Span> = None;
+        let mut field_aliases: Option<(Vec<Ident>, Span)> = None;
+        let mut bindings: Vec<(Ident, syn::Expr)> = Vec::new();
+        let mut group: Option<syn::LitStr> = None;
+        let mut quote = false;
+        let mut join: Option<syn::LitStr> = None;
+        let mut with: Option<syn::Path> = None;
+        let mut letter_case: Option<(LetterCase, syn::Path)> = None;
+        let mut default: Option<Span> = None;
+        let mut debug_fields: Option<Span> = None;
+        let mut all_fields: Option<syn::LitStr> = None;
+        let mut dedent = enum_dedent;
+        let mut externs: Vec<Ident> = Vec::new();
+        let mut cast: Option<syn::Type> = None;
+        let mut trim: Option<Span> = None;
+        let mut truncate: Option<(usize, Span)> = None;
+        let mut field_repr: Option<FieldRepr> = None;
+        let mut group_digits: Option<(Vec<syn::NestedMeta>, Span)> = None;
+        let mut arg_names: Vec<syn::Path> = Vec::new();
+
+        // Find the display attribute, either #[display("...")] or #[display(format = "...")]. For
+        // crates where another derive already owns `display`, #[enum_display(...)] on a variant
+        // takes the same arguments, with `as = "..."` for the format string.
+        for attr in attrs.into_iter() {
+            if use_serde_rename && attr.path.is_ident("serde") {
+                serde_rename = parse_serde_rename(&attr).or(serde_rename);
+            }
+            let attr_name = if attr.path.is_ident("display") {
+                "display"
+            } else if attr.path.is_ident("enum_display") {
+                "enum_display"
+            } else {
+                continue;
+            };
+            for arg in parse_attr_args(&attr)? {
+                arg_names.extend(arg_name(&arg).cloned());
+                match arg {
+                    AttrArg::Nested(syn::NestedMeta::Lit(syn::Lit::Str(lit_str))) => {
+                        set_format(&mut format, lit_str)?;
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("format") || name_value.path.is_ident("as") =>
+                    {
+                        set_format(&mut format, expect_lit_str(name_value.lit)?)?;
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("group") =>
+                    {
+                        group = Some(expect_lit_str(name_value.lit)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("join") =>
+                    {
+                        join = Some(expect_lit_str(name_value.lit)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("with") =>
+                    {
+                        with = Some(expect_lit_str(name_value.lit)?.parse()?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("case") =>
+                    {
+                        // Override the enum's case transform for this variant
+                        let lit_str = expect_lit_str(name_value.lit)?;
+                        case_transform = Some(parse_case_lit(&lit_str)?);
+                    }
+                    AttrArg::NamePath(key, value) if key.is_ident("case") => {
+                        case_transform = Some(parse_case_path(&value)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("field_repr") =>
+                    {
+                        field_repr = Some(parse_field_repr(&expect_lit_str(name_value.lit)?)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("cast") =>
+                    {
+                        cast = Some(expect_lit_str(name_value.lit)?.parse()?);
+                    }
+                    AttrArg::NamePath(key, value) if key.is_ident("cast") => {
+                        cast = Some(syn::parse_quote! { #value });
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("transparent") =>
+                    {
+                        // Forward to the single field's Display
+                        transparent = Some(path.span());
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("quote") =>
+                    {
+                        quote = true;
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("default") =>
+                    {
+                        default = Some(path.span());
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("debug_fields") =>
+                    {
+                        debug_fields = Some(path.span());
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("all_fields") =>
+                    {
+                        all_fields = Some(syn::LitStr::new(", ", path.span()));
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("all_fields") =>
+                    {
+                        all_fields = Some(expect_lit_str(name_value.lit)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("dedent") =>
+                    {
+                        dedent = true;
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("trim") =>
+                    {
+                        trim = Some(path.span());
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                        if name_value.path.is_ident("truncate") =>
+                    {
+                        let max = match &name_value.lit {
+                            syn::Lit::Int(int) => int.base10_parse::<usize>()?,
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "expected the number of characters to keep",
+                                ))
+                            }
+                        };
+                        if max == 0 {
+                            return Err(syn::Error::new_spanned(
+                                &name_value.lit,
+                                "`truncate` has to keep at least one character",
+                            ));
+                        }
+                        truncate = Some((max, name_value.span()));
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if path.is_ident("uppercase") || path.is_ident("lowercase") =>
+                    {
+                        let case = if path.is_ident("uppercase") {
+                            LetterCase::Upper
+                        } else {
+                            LetterCase::Lower
+                        };
+                        if letter_case.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                path,
+                                "only one of `uppercase` and `lowercase` can be used",
+                            ));
+                        }
+                        letter_case = Some((case, path));
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                        if list.path.is_ident("fields") =>
+                    {
+                        field_aliases =
+                            Some((parse_names(&list, "expected a field name")?, list.span()));
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                        if list.path.is_ident("bind") =>
+                    {
+                        bindings.extend(parse_bindings(&list)?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                        if list.path.is_ident("group_digits") =>
+                    {
+                        group_digits = Some((list.nested.into_iter().collect(), list.path.span()));
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                        if list.path.is_ident("extern") =>
+                    {
+                        externs.extend(parse_names(&list, "expected the name of a constant")?);
+                    }
+                    AttrArg::Nested(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                        if is_symbolic_output(&path) =>
+                    {
+                        let output = path
+                            .segments
+                            .iter()
+                            .map(|segment| segment.ident.unraw().to_string())
+                            .collect::<Vec<_>>()
+                            .join("::");
+                        set_format(&mut format, syn::LitStr::new(&output, path.span()))?;
+                    }
+                    arg => return Err(unknown_display_arg(arg, attr_name)),
+                }
+            }
+        }
+
+        if strict {
+            check_repeated_args(&arg_names, &["bind", "extern"])?;
+        }
+
+        if dedent {
+            format = format.map(|format| dedent_lit(&format));
+        }
+
+        if let Some(join) = &join {
+            if format.is_some() || transparent.is_some() {
+                return Err(syn::Error::new(
+                    join.span(),
+                    "`join` can't be combined with a format string or `transparent`",
+                ));
+            }
+        }
+
+        if let Some(span) = debug_fields {
+            if format.is_some() || transparent.is_some() || join.is_some() || with.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`debug_fields` can't be combined with a format string, `join`, `with` or `transparent`",
+                ));
+            }
+        }
+
+        if let Some(separator) = &all_fields {
+            if format.is_some()
+                || transparent.is_some()
+                || join.is_some()
+                || with.is_some()
+                || debug_fields.is_some()
+            {
+                return Err(syn::Error::new(
+                    separator.span(),
+                    "`all_fields` can't be combined with a format string, `join`, `with`, `debug_fields` or `transparent`",
+                ));
+            }
+        }
+
+        if let (Some(_), Some((_, span))) = (&with, truncate) {
+            return Err(syn::Error::new(
+                span,
+                "`truncate` can't be combined with `with`",
+            ));
+        }
+
+        if let (Some(_), Some((_, path))) = (&with, &letter_case) {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!(
+                    "`{}` can't be combined with `with`",
+                    path.get_ident().unwrap()
+                ),
+            ));
+        }
+
+        if let Some(with) = &with {
+            if format.is_some() || transparent.is_some() || join.is_some() || group.is_some() {
+                return Err(syn::Error::new_spanned(
+                    with,
+                    "`with` can't be combined with a format string, `join`, `group` or `transparent`",
+                ));
+            }
+        }
+
+        // A serde rename is a plain name, used only when #[display] doesn't say otherwise
+        if let (None, None, None, None, None, None, None, Some(rename)) = (
+            &format,
+            transparent,
+            &group,
+            &join,
+            &with,
+            debug_fields,
+            &all_fields,
+            serde_rename,
+        ) {
+            format = Some(syn::LitStr::new(
+                &escape_braces(&rename.value()),
+                rename.span(),
+            ));
+        }
+
+        if let (Some(span), Some(_)) = (transparent, &format) {
+            return Err(syn::Error::new(
+                span,
+                "`transparent` can't be combined with a format string",
+            ));
+        }
+
+        Ok(Self {
+            format,
+            case_transform,
+            transparent,
+            field_aliases,
+            bindings,
+            group,
+            quote,
+            join,
+            with,
+            field_renames: Vec::new(),
+            letter_case: letter_case.map(|(case, _)| case),
+            default,
+            debug_fields,
+            all_fields,
+            externs,
+            cast,
+            trim,
+            truncate,
+            field_repr,
+            group_digits,
+            grouped_fields: Vec::new(),
+        })
+    }
+
+    // Named fields can also be referred to in the enum's `rename_all_fields` case, unless that
+    // gives the same name or one another field already has
+    fn resolve_field_renames(
+        &mut self,
+        fields: &syn::Fields,
+        rename_all_fields: Option<(CaseTransform, Span)>,
+        boundaries: Option<&[Boundary]>,
+    ) -> syn::Result<()> {
+        let (case_transform, span) = match rename_all_fields {
+            Some(rename_all_fields) => rename_all_fields,
+            None => return Ok(()),
+        };
+        let names: Vec<Ident> = fields
+            .iter()
+            .filter_map(|field| field.ident.clone())
+            .collect();
+        for field in &names {
+            let renamed =
+                transform_case(field.unraw().to_string(), Some(case_transform), boundaries);
+            let alias: Ident = syn::parse_str(&renamed).map_err(|_| {
+                syn::Error::new(
+                    span,
+                    format!(
+                        "`rename_all_fields` turns `{}` into `{}`, which isn't a valid name",
+                        field, renamed
+                    ),
+                )
+            })?;
+            if !names.contains(&alias) {
+                self.field_renames.push((alias, field.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    // Writing the fields like derived Debug is a format string with a `{N:?}` for every field,
+    // labelled with the field's name for named variants. Without fields it's just the name.
+    fn resolve_debug_fields(&mut self, fields: &syn::Fields, enum_default: bool) {
+        let has_own_format = self.format.is_some()
+            || self.transparent.is_some()
+            || self.with.is_some()
+            || self.group.is_some();
+        let enabled = self.debug_fields.is_some() || (enum_default && !has_own_format);
+        if !enabled || (fields.is_empty() && self.debug_fields.is_none()) {
+            return;
+        }
+        let values = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => format!("{}: {{{}:?}}", ident.unraw(), i),
+                None => format!("{{{}:?}}", i),
+            });
+        let values: Vec<String> = values.collect();
+        let format = match fields {
+            _ if fields.is_empty() => "{variant}".to_string(),
+            syn::Fields::Named(_) => format!("{{variant}} {{{{ {} }}}}", values.join(", ")),
+            _ => format!("{{variant}}({})", values.join(", ")),
+        };
+        let span = self.debug_fields.unwrap_or_else(Span::call_site);
+        self.format = Some(syn::LitStr::new(&format, span));
+    }
+
+    // Spreading the fields is a format string with a `name: {N}` for every named field, a variant
+    // without any is written with empty braces
+    fn resolve_all_fields(&mut self, fields: &syn::Fields) -> syn::Result<()> {
+        let separator = match &self.all_fields {
+            Some(separator) => separator,
+            None => return Ok(()),
+        };
+        if !matches!(fields, syn::Fields::Named(_)) {
+            return Err(syn::Error::new(
+                separator.span(),
+                "`all_fields` can only be used on variants with named fields",
+            ));
+        }
+        let values: Vec<String> = fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, field)| Some(format!("{}: {{{}}}", field.ident.as_ref()?.unraw(), i)))
+            .collect();
+        let format = if values.is_empty() {
+            "{variant} {{}}".to_string()
+        } else {
+            format!(
+                "{{variant}} {{{{ {} }}}}",
+                values.join(&escape_braces(&separator.value()))
+            )
+        };
+        self.format = Some(syn::LitStr::new(&format, separator.span()));
+        Ok(())
+    }
+
+    // The field a format string argument refers to, by position or by any of its names.
+    // `{variant}` and `{enum}` are the names even when a field is called that, and a binding
+    // shadows a field's name.
+    fn field_position(&self, arg: &str, fields: &syn::Fields) -> Option<usize> {
+        if matches!(arg, "variant" | "enum") || self.bindings.iter().any(|(name, _)| name == arg) {
+            return None;
+        }
+        if let Ok(index) = arg.parse::<usize>() {
+            return (index < fields.len()).then_some(index);
+        }
+        let renamed = self
+            .field_renames
+            .iter()
+            .find(|(alias, _)| alias == arg)
+            .map(|(_, field)| field.unraw().to_string());
+        let name = renamed.as_deref().unwrap_or(arg);
+        let aliases: &[Ident] = self
+            .field_aliases
+            .as_ref()
+            .map_or(&[], |(aliases, _)| aliases.as_slice());
+        fields
+            .iter()
+            .position(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| ident.unraw() == name)
+            })
+            .or_else(|| aliases.iter().position(|alias| alias == arg))
+            .or_else(|| match fields {
+                syn::Fields::Unnamed(_) if arg == "last" => fields.len().checked_sub(1),
+                _ => None,
+            })
+    }
+
+    // With `field_repr = "debug"`, every placeholder for a field without a spec of its own gets a
+    // `:?`. Bindings and constants keep using `Display`, and a binding shadows a field's name.
+    fn resolve_field_repr(&mut self, fields: &syn::Fields, enum_default: FieldRepr) {
+        if self.field_repr.unwrap_or(enum_default) != FieldRepr::Debug {
+            return;
+        }
+        let format = match &self.format {
+            Some(format) => format,
+            None => return,
+        };
+        let is_field = |arg: &str| self.field_position(arg, fields).is_some();
+        let re = regex!(r"\{\{|\}\}|\{\s*(\d+|[A-Za-z_][A-Za-z0-9_]*)\s*\}");
+        let rewritten = re
+            .replace_all(&format.value(), |caps: &regex::Captures| {
+                match caps.get(1) {
+                    Some(arg) if is_field(arg.as_str()) => format!("{{{}:?}}", arg.as_str()),
+                    _ => caps[0].to_string(),
+                }
+            })
+            .to_string();
+        self.format = Some(syn::LitStr::new(&rewritten, format.span()));
+    }
+
+    // Every placeholder for a field in `group_digits` is pointed at a binding of the field wrapped
+    // in the facade's `GroupDigits`, keeping its spec. The field itself is left as it is, so
+    // bindings computed from it still see the number.
+    fn resolve_group_digits(&mut self, fields: &syn::Fields) -> syn::Result<()> {
+        let (list, span) = match &self.group_digits {
+            Some(group_digits) => group_digits,
+            None => return Ok(()),
+        };
+        let format = match &self.format {
+            Some(format) if self.transparent.is_none() => format,
+            _ => {
+                return Err(syn::Error::new(
+                    *span,
+                    "`group_digits` can only be used on variants with a format string",
+                ))
+            }
+        };
+        let mut grouped_fields = Vec::new();
+        for nested in list {
+            let arg = match nested {
+                syn::NestedMeta::Lit(syn::Lit::Int(int)) => int.base10_digits().to_string(),
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.get_ident().is_some() => {
+                    path.get_ident().unwrap().to_string()
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "expected a field name or position",
+                    ))
+                }
+            };
+            match self.field_position(&arg, fields) {
+                Some(index) if grouped_fields.contains(&index) => {}
+                Some(index) => grouped_fields.push(index),
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        format!("the variant has no field `{}` to group the digits of", arg),
+                    ))
+                }
+            }
+        }
+        let re = regex!(r"\{\{|\}\}|\{\s*(\d+|[A-Za-z_][A-Za-z0-9_]*)\s*(:[^}]*)?\}");
+        let rewritten = re
+            .replace_all(&format.value(), |caps: &regex::Captures| {
+                let index = caps
+                    .get(1)
+                    .and_then(|arg| self.field_position(arg.as_str(), fields))
+                    .filter(|index| grouped_fields.contains(index));
+                match index {
+                    Some(index) => format!(
+                        "{{__enum_display_grouped_{}{}}}",
+                        index,
+                        caps.get(2).map_or("", |spec| spec.as_str())
+                    ),
+                    None => caps[0].to_string(),
+                }
+            })
+            .to_string();
+        self.format = Some(syn::LitStr::new(&rewritten, format.span()));
+        self.grouped_fields = grouped_fields;
+        Ok(())
+    }
+
+    // A joined tuple variant is written as a format string with a placeholder for every field
+    fn resolve_join(&mut self, fields: &syn::Fields) -> syn::Result<()> {
+        let join = match &self.join {
+            Some(join) => join,
+            None => return Ok(()),
+        };
+        if !matches!(fields, syn::Fields::Unnamed(_)) {
+            return Err(syn::Error::new(
+                join.span(),
+                "`join` can only be used on tuple variants",
+            ));
+        }
+        let placeholders: Vec<String> = (0..fields.len()).map(|i| format!("{{{}}}", i)).collect();
+        self.format = Some(syn::LitStr::new(
+            &placeholders.join(&escape_braces(&join.value())),
+            join.span(),
+        ));
+        Ok(())
+    }
+
+    // A grouped variant displays as its group's shared string, written as a plain name
+    fn resolve_group(&mut self, groups: &[(Ident, syn::LitStr)]) -> syn::Result<()> {
+        let group = match &self.group {
+            Some(group) => group,
+            None => return Ok(()),
+        };
+        if self.format.is_some()
+            || self.transparent.is_some()
+            || self.join.is_some()
+            || self.debug_fields.is_some()
+            || self.all_fields.is_some()
+        {
+            return Err(syn::Error::new(
+                group.span(),
+                "a grouped variant can't have its own format string or be `transparent`",
+            ));
+        }
+        let (_, output) = groups
+            .iter()
+            .find(|(name, _)| *name == group.value())
+            .ok_or_else(|| {
+                let names: Vec<String> = groups.iter().map(|(name, _)| name.to_string()).collect();
+                syn::Error::new(
+                    group.span(),
+                    format!(
+                        "unknown group `{}`, declared groups are: {}",
+                        group.value(),
+                        names.join(", ")
+                    ),
+                )
+            })?;
+        self.format = Some(syn::LitStr::new(
+            &escape_braces(&output.value()),
+            group.span(),
+        ));
+        Ok(())
+    }
+
+    // Check the attributes make sense for the variant's fields
+    fn validate(&self, fields: &syn::Fields, has_discriminant: bool) -> syn::Result<()> {
+        if let Some(span) = self.transparent {
+            if fields.len() != 1 {
+                return Err(syn::Error::new(
+                    span,
+                    "`transparent` can only be used on variants with exactly one field",
+                ));
+            }
+        }
+
+        if let Some((aliases, span)) = &self.field_aliases {
+            match fields {
+                syn::Fields::Unnamed(_) if fields.len() != aliases.len() => {
+                    return Err(syn::Error::new(
+                        *span,
+                        format!(
+                            "expected {} field names to match the variant's fields, found {}",
+                            fields.len(),
+                            aliases.len()
+                        ),
+                    ));
+                }
+                syn::Fields::Unnamed(_) => {}
+                _ => {
+                    return Err(syn::Error::new(
+                        *span,
+                        "`fields` can only be used on tuple variants",
+                    ));
+                }
+            }
+        }
+
+        // A name without a format string has nothing around it to trim
+        if let Some(span) = self.trim {
+            if self.format.is_none() || self.transparent.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`trim` can only be used on variants with a format string",
+                ));
+            }
+        }
+
+        // The cast fields are only bound for a format string to write
+        if let Some(cast) = &self.cast {
+            if !matches!(fields, syn::Fields::Unnamed(_))
+                || self.format.is_none()
+                || self.transparent.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    cast,
+                    "`cast` can only be used on tuple variants with a format string",
+                ));
+            }
+        }
+
+        if let Some(format) = &self.format {
+            check_format_syntax(&format.value())
+                .map_err(|message| syn::Error::new(format.span(), message))?;
+            self.validate_placeholders(format, fields, has_discriminant)?;
+        }
+
+        Ok(())
+    }
+
+    // Every named placeholder has to be something the generated code binds, otherwise `write!`
+    // reports the typo from inside the expansion instead of on the format string
+    fn validate_placeholders(
+        &self,
+        format: &syn::LitStr,
+        fields: &syn::Fields,
+        has_discriminant: bool,
+    ) -> syn::Result<()> {
+        let mut available = vec![
+            "variant".to_string(),
+            "enum".to_string(),
+            "arity".to_string(),
+        ];
+        if has_discriminant {
+            available.push("discriminant".to_string());
+        }
+        available.extend(
+            fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .map(|ident| ident.unraw().to_string()),
+        );
+        available.extend(
+            self.field_aliases
+                .iter()
+                .flat_map(|(aliases, _)| aliases.iter().map(|alias| alias.to_string())),
+        );
+        available.extend(
+            self.field_renames
+                .iter()
+                .map(|(alias, _)| alias.to_string()),
+        );
+        available.extend(self.bindings.iter().map(|(name, _)| name.to_string()));
+        // Anything else `write!` captures from the scope the enum is declared in
+        available.extend(self.externs.iter().map(Ident::to_string));
+        // `{last}` is the last field of a tuple variant, unless something else is called that
+        let has_last = matches!(fields, syn::Fields::Unnamed(_)) && !fields.is_empty();
+        if has_last {
+            available.push("last".to_string());
+        }
+
+        // Positional placeholders index into the fields whether they're named or not, and so do
+        // positional widths and precisions like `{0:1$}`
+        let count = regex!(r"0?(\d+)\$");
+        let mut positions = Vec::new();
+        for (arg, spec) in placeholders(&format.value()) {
+            let counts: Vec<String> = count
+                .captures_iter(&spec)
+                .map(|caps| caps[1].to_string())
+                .collect();
+            for index in std::iter::once(&arg)
+                .chain(&counts)
+                .filter_map(|index| index.parse::<usize>().ok())
+            {
+                if index >= fields.len() {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        format!(
+                            "no field at position {} in format string, the variant has {} field{}",
+                            index,
+                            fields.len(),
+                            if fields.len() == 1 { "" } else { "s" }
+                        ),
+                    ));
+                }
+                positions.push(index);
+            }
+        }
+
+        for name in named_placeholders(&format.value()) {
+            // `{variant}` and `{enum}` always mean the names, so with a field called either that
+            // nothing else refers to it's unclear which one was meant
+            if name == "variant" || name == "enum" {
+                let field = fields.iter().enumerate().find(|(index, field)| {
+                    let ident = match &field.ident {
+                        Some(ident) if ident.unraw() == name => ident,
+                        _ => return false,
+                    };
+                    !positions.contains(index)
+                        && !self.field_renames.iter().any(|(_, field)| field == ident)
+                        && !self
+                            .bindings
+                            .iter()
+                            .any(|(_, expr)| mentions_params(quote! { #expr }, &[ident]))
+                });
+                if let Some((index, field)) = field {
+                    return Err(syn::Error::new_spanned(
+                        &field.ident,
+                        format!(
+                            "`{{{}}}` in the format string is the {} name, not this field, write \
+                             `{{{}}}` to use the field",
+                            name, name, index
+                        ),
+                    ));
+                }
+            }
+            if name == "last" && !available.contains(&name) {
+                return Err(syn::Error::new(
+                    format.span(),
+                    "`{last}` can only be used on tuple variants with at least one field",
+                ));
+            }
+            if name == "discriminant" && !available.contains(&name) {
+                return Err(syn::Error::new(
+                    format.span(),
+                    "`{discriminant}` can only be used on enum variants",
+                ));
+            }
+            if !available.contains(&name) {
+                // Upper case names are most likely constants that weren't listed
+                let hint = if name.chars().any(char::is_uppercase)
+                    && !name.chars().any(char::is_lowercase)
+                {
+                    format!(", or list `{}` in `extern(...)` if it's a constant", name)
+                } else {
+                    String::new()
+                };
+                return Err(syn::Error::new(
+                    format.span(),
+                    format!(
+                        "no field named `{}` in format string, available names are: {}{}",
+                        name,
+                        available.join(", "),
+                        hint
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Everything shared by the variant kinds
+struct VariantInfo {
+    ident: Ident,
+    ident_transformed: String,
+    // The name `{variant}` refers to in format strings
+    variant_name: String,
+    enum_name: String,
+    attrs: VariantAttrs,
+    prefix: String,
+    suffix: String,
+    pad: bool,
+    quote: bool,
+    // Escape the quoted output for JSON, #[enum_display(json)]
+    json: bool,
+    // Write numeric fields without `write!` when possible, #[enum_display(fast_numeric)]
+    fast_numeric: bool,
+    crate_path: syn::Path,
+    // The value `{discriminant}` is bound to with its type, `None` for structs
+    discriminant: Option<(TokenStream2, TokenStream2)>,
+    // The number of fields `{arity}` is, `None` when a field is called `arity` itself
+    arity: Option<usize>,
+}
+
+impl VariantInfo {
+    // The string written for a variant without a format string
+    fn display_name(&self) -> String {
+        self.quoted(self.cased(self.truncated(format!(
+            "{}{}{}",
+            self.prefix, self.ident_transformed, self.suffix
+        ))))
+    }
+
+    // Cut the output off after `truncate` characters, the way the facade's `Truncate` writer does
+    fn truncated(&self, output: String) -> String {
+        match self.attrs.truncate {
+            Some((max, _)) if output.chars().count() > max => {
+                let mut truncated: String = output.chars().take(max).collect();
+                truncated.push('…');
+                truncated
+            }
+            _ => output,
+        }
+    }
+
+    fn cased(&self, output: String) -> String {
+        match self.attrs.letter_case {
+            Some(LetterCase::Upper) => output.to_uppercase(),
+            Some(LetterCase::Lower) => output.to_lowercase(),
+            None => output,
+        }
+    }
+
+    // Whether the output has to be written through one of the facade's adapting writers
+    fn adapts_output(&self) -> bool {
+        self.quote || self.attrs.letter_case.is_some() || self.attrs.truncate.is_some()
+    }
+
+    fn quoted(&self, output: String) -> String {
+        if self.json {
+            format!("\"{}\"", escape_json(&output))
+        } else if self.quote {
+            format!("\"{}\"", output.replace('\\', "\\\\").replace('"', "\\\""))
+        } else {
+            output
+        }
+    }
+
+    // Write a format string into `writer`, cut off by the facade when #[display(truncate)] asks
+    // for it. Trimming happens first, so the whitespace it drops doesn't count.
+    fn write_fmt(&self, writer: TokenStream2, fmt: &str) -> TokenStream2 {
+        let max = match self.attrs.truncate {
+            Some((max, _)) => max,
+            None => return self.write_trimmed(writer, fmt),
+        };
+        let crate_path = &self.crate_path;
+        let write = self.write_trimmed(quote! { &mut __enum_display_truncate }, fmt);
+        quote! {
+            {
+                let mut __enum_display_truncate =
+                    #crate_path::__private::Truncate::new(#writer, #max);
+                #write?;
+                __enum_display_truncate.finish()
+            }
+        }
+    }
+
+    // Write a format string into `writer`, trimmed by the facade when #[display(trim)] asks for it
+    fn write_trimmed(&self, writer: TokenStream2, fmt: &str) -> TokenStream2 {
+        if self.attrs.trim.is_none() {
+            return quote! { ::core::write!(#writer, #fmt) };
+        }
+        let crate_path = &self.crate_path;
+        quote! { #crate_path::__private::write_trimmed(#writer, ::core::format_args!(#fmt)) }
+    }
+
+    // Write a formatted variant, through writers that change its case or escape its quotes
+    // when it asks for that
+    fn write_formatted(&self, fmt: &str, bindings: TokenStream2) -> TokenStream2 {
+        self.write_formatted_to(&formatter(), fmt, bindings)
+    }
+
+    // Like `write_formatted`, but into `writer`, either the formatter or the `fmt::Write` given
+    // to `display_into`
+    fn write_formatted_to(
+        &self,
+        writer: &Ident,
+        fmt: &str,
+        bindings: TokenStream2,
+    ) -> TokenStream2 {
+        let f = writer;
+        if !self.adapts_output() {
+            let write = self.write_fmt(quote! { #f }, fmt);
+            return quote! {
+                {
+                    #bindings
+                    #write
+                }
+            };
+        }
+        let crate_path = &self.crate_path;
+        let mut writer = quote! { &mut *#f };
+        if let Some(case) = self.attrs.letter_case {
+            let adapter = match case {
+                LetterCase::Upper => quote! { Uppercase },
+                LetterCase::Lower => quote! { Lowercase },
+            };
+            writer = quote! { #crate_path::__private::#adapter(#writer) };
+        }
+        if !self.quote {
+            let write = self.write_fmt(writer, fmt);
+            return quote! {
+                {
+                    #bindings
+                    #write
+                }
+            };
+        }
+        let escape = if self.json {
+            quote! { EscapeJson }
+        } else {
+            quote! { EscapeQuotes }
+        };
+        let write = self.write_fmt(quote! { #crate_path::__private::#escape(#writer) }, fmt);
+        quote! {
+            {
+                #bindings
+                ::core::fmt::Write::write_str(#f, "\"")?;
+                #write?;
+                ::core::fmt::Write::write_str(#f, "\"")
+            }
+        }
+    }
+
+    // With `fast_numeric`, a format string that only interpolates integer fields without a spec
+    // is written a piece at a time, the numbers with `itoa`. `names` are
+    // what the fields can be referred to by besides their position. Anything else, or output
+    // that has to go through an adapting writer, is left to `write!`.
+    fn fast_numeric_body(
+        &self,
+        names: &[String],
+        fields: &[Ident],
+        types: &[syn::Type],
+    ) -> Option<TokenStream2> {
+        let f = formatter();
+        if !self.fast_numeric
+            || self.adapts_output()
+            || self.attrs.trim.is_some()
+            || self.attrs.cast.is_some()
+            || !self.attrs.grouped_fields.is_empty()
+            || !self.attrs.bindings.is_empty()
+        {
+            return None;
+        }
+        let fmt = self.format_string()?;
+        let crate_path = &self.crate_path;
+        // Text next to `{variant}` and `{enum}` is merged into one `write_str`
+        let mut writes = Vec::new();
+        let mut text = String::new();
+        let mut any_numeric = false;
+        for piece in format_pieces(&fmt) {
+            let (arg, spec) = match piece {
+                FormatPiece::Text(piece) => {
+                    text.push_str(&piece);
+                    continue;
+                }
+                FormatPiece::Placeholder(arg, spec) => (arg, spec),
+            };
+            if !spec.is_empty() {
+                return None;
+            }
+            match arg.as_str() {
+                "variant" => text.push_str(&self.variant_name),
+                "enum" => text.push_str(&self.enum_name),
+                _ => {
+                    let index = arg
+                        .parse::<usize>()
+                        .ok()
+                        .or_else(|| names.iter().position(|name| *name == arg))?;
+                    let field = fields.get(index)?;
+                    let writer = numeric_writer(types.get(index)?)?;
+                    if !text.is_empty() {
+                        let text = std::mem::take(&mut text);
+                        writes.push(quote! { ::core::fmt::Formatter::write_str(#f, #text)?; });
+                    }
+                    writes.push(quote! { #crate_path::__private::#writer(#f, *#field)?; });
+                    any_numeric = true;
+                }
+            }
+        }
+        if !text.is_empty() {
+            writes.push(quote! { ::core::fmt::Formatter::write_str(#f, #text)?; });
+        }
+        any_numeric.then(|| {
+            quote! {
+                {
+                    #(#writes)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        })
+    }
+
+    // The function a plain name is written with, see `write_name`
+    fn write_name(&self) -> TokenStream2 {
+        write_name(self.pad)
+    }
+
+    // The format string written for a formatted variant, wrapped in the enum's prefix and suffix
+    fn format_string(&self) -> Option<String> {
+        self.attrs.format.as_ref().map(|fmt| {
+            format!(
+                "{}{}{}",
+                escape_braces(&self.prefix),
+                fmt.value(),
+                escape_braces(&self.suffix)
+            )
+        })
+    }
+
+    // The fields in `group_digits`, wrapped so they're written with their digits grouped
+    fn grouped_bindings(&self, fields: &[Ident]) -> TokenStream2 {
+        let crate_path = &self.crate_path;
+        let bindings = self.attrs.grouped_fields.iter().map(|&index| {
+            let field = &fields[index];
+            let grouped = format_ident!("__enum_display_grouped_{}", index);
+            quote! { let #grouped = #crate_path::__private::GroupDigits(#field); }
+        });
+        quote! { #(#bindings)* }
+    }
+
+    // The values a format string can refer to besides the variant's fields: the variant and
+    // enum names, any names given to tuple `fields`, then the user's own `bind` expressions
+    fn format_bindings(&self, fields: &[Ident]) -> TokenStream2 {
+        let variant_name = &self.variant_name;
+        let enum_name = &self.enum_name;
+        let aliases = self
+            .attrs
+            .field_aliases
+            .iter()
+            .flat_map(|(aliases, _)| aliases.iter().zip(fields))
+            .map(|(alias, field)| quote! { let #alias = #field; });
+        let renames = self
+            .attrs
+            .field_renames
+            .iter()
+            .filter(|(alias, _)| self.references(&alias.to_string()))
+            .map(|(alias, field)| quote! { let #alias = #field; });
+        let bindings = self
+            .attrs
+            .bindings
+            .iter()
+            .map(|(name, expr)| quote! { let #name = #expr; });
+        // Only bound when the format string asks for it, so it can't shadow anything otherwise
+        let last = fields
+            .last()
+            .filter(|_| self.references("last"))
+            .map(|field| quote! { let last = #field; });
+        let discriminant = self
+            .discriminant
+            .as_ref()
+            .filter(|_| self.references("discriminant"))
+            .map(|(ty, value)| quote! { let discriminant: #ty = #value; });
+        let arity = self
+            .arity
+            .filter(|_| self.references("arity"))
+            .map(|arity| quote! { let arity: usize = #arity; });
+        quote! {
+            let __enum_display_variant = #variant_name;
+            let __enum_display_enum = #enum_name;
+            #discriminant
+            #arity
+            #last
+            #(#aliases)*
+            #(#renames)*
+            #(#bindings)*
+        }
+    }
+
+    // Whether the format string has a placeholder for `name`
+    fn references(&self, name: &str) -> bool {
+        self.attrs.format.as_ref().is_some_and(|format| {
+            named_placeholders(&format.value())
+                .iter()
+                .any(|placeholder| placeholder == name)
+        })
+    }
+
+    // A transparent variant hands the formatter to its field, so every flag like `{:#}` or a width
+    // reaches the field's own `Display`. Only the enum's prefix and suffix are written around it.
+    fn transparent_body(&self, field: &Ident) -> TokenStream2 {
+        let f = formatter();
+        // Quoting and case changes need their own writers, so the field is written with `write!`
+        if self.adapts_output() {
+            let fmt = format!(
+                "{}{{__enum_display_field}}{}",
+                escape_braces(&self.prefix),
+                escape_braces(&self.suffix)
+            );
+            return self.write_formatted(&fmt, quote! { let __enum_display_field = #field; });
+        }
+        self.wrap_affixes(quote! { ::core::fmt::Display::fmt(#field, #f) })
+    }
+
+    // A variant written by a `with` function, which gets the formatter and every field in
+    // declaration order
+    fn with_body(&self, with: &syn::Path, fields: &[Ident]) -> TokenStream2 {
+        let f = formatter();
+        self.wrap_affixes(quote! { #with(#f, #(#fields),*) })
+    }
+
+    // Write the enum's prefix and suffix around a call that writes into the formatter itself
+    fn wrap_affixes(&self, call: TokenStream2) -> TokenStream2 {
+        let f = formatter();
+        if self.prefix.is_empty() && self.suffix.is_empty() {
+            return call;
+        }
+        let prefix = &self.prefix;
+        let suffix = &self.suffix;
+        quote! {
+            {
+                ::core::fmt::Formatter::write_str(#f, #prefix)?;
+                #call?;
+                ::core::fmt::Formatter::write_str(#f, #suffix)
+            }
+        }
+    }
+
+    // The string this variant always displays as, if it can be known at expansion time.
+    // Format strings only qualify when they contain nothing but escaped braces and `{variant}`.
+    fn static_display(&self) -> Option<String> {
+        if self.attrs.transparent.is_some() || self.attrs.with.is_some() {
+            return None;
+        }
+        let fmt = match self.format_string() {
+            Some(fmt) => fmt,
+            None => return Some(self.display_name()),
+        };
+
+        let mut output = String::new();
+        let mut rest = fmt.as_str();
+        while let Some(c) = rest.chars().next() {
+            if let Some(tail) = rest.strip_prefix("{{") {
+                output.push('{');
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix("}}") {
+                output.push('}');
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix("{variant}") {
+                output.push_str(&self.variant_name);
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix("{enum}") {
+                output.push_str(&self.enum_name);
+                rest = tail;
+            } else if let (Some(tail), Some(arity)) = (rest.strip_prefix("{arity}"), self.arity) {
+                output.push_str(&arity.to_string());
+                rest = tail;
+            } else if c == '{' || c == '}' {
+                return None;
+            } else {
+                output.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        if self.attrs.trim.is_some() {
+            output = output.trim().to_string();
+        }
+        Some(self.quoted(self.cased(self.truncated(output))))
+    }
+}
+
+struct NamedVariantIR {
+    info: VariantInfo,
+    fields: Vec<Ident>,
+    types: Vec<syn::Type>,
+}
+
+impl NamedVariantIR {
+    fn fast_numeric_body(&self) -> Option<TokenStream2> {
+        let names: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| field.unraw().to_string())
+            .collect();
+        self.info
+            .fast_numeric_body(&names, &self.fields, &self.types)
+    }
+
+    fn from_fields_named(fields_named: FieldsNamed, info: VariantInfo) -> Self {
+        let (fields, types) = fields_named
+            .named
+            .into_iter()
+            .map(|field| (field.ident.unwrap(), field.ty))
+            .unzip();
+        Self {
+            info,
+            fields,
+            types,
+        }
+    }
+
+    // The match arm of a variant with a format string, writing into `writer`
+    fn formatted_arm(&self, writer: &Ident, fmt: String) -> TokenStream2 {
+        let ident = &self.info.ident;
+        let fields = &self.fields;
+        // Positional placeholders refer to the named fields in declaration order. A binding
+        // shadows the field's name, and `{variant}` and `{enum}` are the names, so such a field is
+        // bound under a name of its own first for `{N}` to still mean the field.
+        let mut positional = Vec::new();
+        let positions: Vec<Ident> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let shadowed = field.unraw() == "variant"
+                    || field.unraw() == "enum"
+                    || self
+                        .info
+                        .attrs
+                        .bindings
+                        .iter()
+                        .any(|(name, _)| name == field);
+                if !shadowed {
+                    return field.clone();
+                }
+                let position = format_ident!("__enum_display_field_{}", i);
+                positional.push(quote! { let #position = #field; });
+                position
+            })
+            .collect();
+        let fmt = translate_name_placeholders(&translate_numeric_placeholders(&fmt, &positions));
+        let grouped = self.info.grouped_bindings(fields);
+        let bindings = self.info.format_bindings(&[]);
+        let body = self.info.write_formatted_to(
+            writer,
+            &fmt,
+            quote! {
+                #grouped
+                #(#positional)*
+                #bindings
+            },
+        );
+        quote! {
+            #ident { #(#fields),* } => #body
+        }
+    }
+
+    fn generate(&self, any_has_format: bool) -> TokenStream2 {
+        let f = formatter();
+        let display_name = self.info.display_name();
+        let ident = &self.info.ident;
+        let fields = &self.fields;
+
+        if self.info.attrs.transparent.is_some() {
+            let field = &fields[0];
+            let body = self.info.transparent_body(field);
+            return quote! {
+                #ident { #field } => #body,
+            };
+        }
+
+        if let Some(with) = &self.info.attrs.with {
+            let body = self.info.with_body(with, fields);
+            return quote! {
+                #ident { #(#fields),* } => #body,
+            };
+        }
+
+        if let Some(body) = self.fast_numeric_body() {
+            return quote! {
+                #ident { #(#fields),* } => #body
+            };
+        }
+
+        match (any_has_format, self.info.format_string()) {
+            (true, Some(fmt)) => self.formatted_arm(&f, fmt),
+            (true, None) => {
+                let write_name = self.info.write_name();
+                quote! {
+                    #ident { .. } => #write_name(#f, #display_name),
+                }
+            }
+            (false, _) => quote! {
+                #ident { .. } => #display_name,
+            },
+        }
+    }
+}
+
+struct UnnamedVariantIR {
+    info: VariantInfo,
+    fields: Vec<Ident>,
+    types: Vec<syn::Type>,
+}
+
+impl UnnamedVariantIR {
+    fn fast_numeric_body(&self) -> Option<TokenStream2> {
+        let names: Vec<String> = self
+            .info
+            .attrs
+            .field_aliases
+            .iter()
+            .flat_map(|(aliases, _)| aliases.iter().map(Ident::to_string))
+            .collect();
+        self.info
+            .fast_numeric_body(&names, &self.fields, &self.types)
+    }
+
+    fn from_fields_unnamed(fields_unnamed: FieldsUnnamed, info: VariantInfo) -> Self {
+        let fields = (0..fields_unnamed.unnamed.len())
+            .map(|i| format_ident!("_unnamed_{}", i))
+            .collect();
+        let types = fields_unnamed
+            .unnamed
+            .into_iter()
+            .map(|field| field.ty)
+            .collect();
+        Self {
+            info,
+            fields,
+            types,
+        }
+    }
+
+    // The match arm of a variant with a format string, writing into `writer`
+    fn formatted_arm(&self, writer: &Ident, fmt: String) -> TokenStream2 {
+        let ident = &self.info.ident;
+        let fields = &self.fields;
+        let fmt = translate_name_placeholders(&translate_numeric_placeholders(&fmt, fields));
+        // Casting first means names given by `fields(...)` and `{last}` see the cast values too
+        let casts = self.info.attrs.cast.iter().flat_map(|ty| {
+            fields
+                .iter()
+                .map(move |field| quote! { let #field = *#field as #ty; })
+        });
+        let grouped = self.info.grouped_bindings(fields);
+        let bindings = self.info.format_bindings(fields);
+        let body = self.info.write_formatted_to(
+            writer,
+            &fmt,
+            quote! {
+                #(#casts)*
+                #grouped
+                #bindings
+            },
+        );
+        quote! {
+            #ident(#(#fields),*) => #body
+        }
+    }
+
+    fn generate(&self, any_has_format: bool) -> TokenStream2 {
+        let f = formatter();
+        let display_name = self.info.display_name();
+        let ident = &self.info.ident;
+        let fields = &self.fields;
+
+        if self.info.attrs.transparent.is_some() {
+            let field = &fields[0];
+            let body = self.info.transparent_body(field);
+            return quote! {
+                #ident(#field) => #body,
+            };
+        }
+
+        if let Some(with) = &self.info.attrs.with {
+            let body = self.info.with_body(with, fields);
+            return quote! {
+                #ident(#(#fields),*) => #body,
+            };
+        }
+
+        if let Some(body) = self.fast_numeric_body() {
+            return quote! {
+                #ident(#(#fields),*) => #body
+            };
+        }
+
+        match (any_has_format, self.info.format_string()) {
+            (true, Some(fmt)) => self.formatted_arm(&f, fmt),
+            (true, None) => {
+                let write_name = self.info.write_name();
+                quote! {
+                    #ident(..) => #write_name(#f, #display_name),
+                }
+            }
+            (false, _) => quote! {
+                #ident(..) => #display_name,
+            },
+        }
+    }
+}
+
+struct UnitVariantIR {
+    info: VariantInfo,
+}
+
+impl UnitVariantIR {
+    // The match arm of a variant with a format string, writing into `writer`
+    fn formatted_arm(&self, writer: &Ident, fmt: String) -> TokenStream2 {
+        let ident = &self.info.ident;
+        // A unit variant has no fields, but this still drops the whitespace a placeholder
+        // is allowed to have, like `{ variant }`
+        let fmt = translate_name_placeholders(&translate_numeric_placeholders(&fmt, &[]));
+        let bindings = self.info.format_bindings(&[]);
+        let body = self.info.write_formatted_to(writer, &fmt, bindings);
+        quote! {
+            #ident => #body
+        }
+    }
+
+    fn generate(&self, any_has_format: bool) -> TokenStream2 {
+        let f = formatter();
+        let display_name = self.info.display_name();
+        let ident = &self.info.ident;
+
+        if let Some(with) = &self.info.attrs.with {
+            let body = self.info.with_body(with, &[]);
+            return quote! {
+                #ident => #body,
+            };
+        }
+
+        match (any_has_format, self.info.format_string()) {
+            (true, Some(fmt)) => self.formatted_arm(&f, fmt),
+            (true, None) => {
+                let write_name = self.info.write_name();
+                quote! {
+                    #ident => #write_name(#f, #display_name),
+                }
+            }
+            (false, _) => quote! {
+                #ident => #display_name,
+            },
+        }
+    }
+}
+
+enum VariantIR {
+    Named(NamedVariantIR),
+    Unnamed(UnnamedVariantIR),
+    Unit(UnitVariantIR),
+}
+
+impl VariantIR {
+    fn from_variant(
+        variant: Variant,
+        discriminant: Option<TokenStream2>,
+        enum_ident: &Ident,
+        enum_attrs: &EnumAttrs,
+    ) -> syn::Result<Self> {
+        // The derive only reads attributes on the variant, one on a field would do nothing
+        if enum_attrs.strict {
+            let misplaced = variant
+                .fields
+                .iter()
+                .flat_map(|field| &field.attrs)
+                .find(|attr| attr.path.is_ident("display") || attr.path.is_ident("enum_display"));
+            if let Some(attr) = misplaced {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "attributes on fields are ignored, put `display` on the variant instead",
+                ));
+            }
+        }
+        let mut attrs = VariantAttrs::from_attrs(
+            variant.attrs,
+            enum_attrs.use_serde_rename,
+            enum_attrs.dedent,
+            enum_attrs.strict,
+        )?;
+        attrs.resolve_group(&enum_attrs.groups)?;
+        attrs.resolve_join(&variant.fields)?;
+        attrs.resolve_all_fields(&variant.fields)?;
+        attrs.resolve_debug_fields(&variant.fields, enum_attrs.debug_fields);
+        attrs.resolve_field_renames(
+            &variant.fields,
+            enum_attrs.rename_all_fields,
+            enum_attrs.boundaries.as_deref(),
+        )?;
+        // Variants without their own format string fall back to the enum's template
+        if attrs.format.is_none() && attrs.transparent.is_none() && attrs.with.is_none() {
+            attrs.format = enum_attrs.format.clone();
+        }
+        attrs.resolve_field_repr(&variant.fields, enum_attrs.field_repr);
+        // A field called `discriminant` is what `{discriminant}` refers to instead
+        let discriminant = discriminant.filter(|_| {
+            !variant.fields.iter().any(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| ident == "discriminant")
+            })
+        });
+        attrs.validate(&variant.fields, discriminant.is_some())?;
+        attrs.resolve_group_digits(&variant.fields)?;
+        // A case set on the variant wins over the one set on the enum, and `{variant}` only
+        // follows the enum's `variant_case` when the variant doesn't set its own
+        let case_transform = attrs.case_transform.or(enum_attrs.case_transform);
+        let variant_case = attrs
+            .case_transform
+            .or(enum_attrs.variant_case)
+            .or(enum_attrs.case_transform);
+        // Raw identifiers like `r#Type` display without their `r#` prefix
+        let name = strip_affixes(
+            variant.ident.unraw().to_string(),
+            &enum_attrs.strip_prefix,
+            &enum_attrs.strip_suffix,
+        );
+        let boundaries = enum_attrs.boundaries.as_deref();
+        let mut ident_transformed = transform_case(name.clone(), case_transform, boundaries);
+        let mut variant_name = transform_case(name, variant_case, boundaries);
+        let enum_name = enum_ident.unraw().to_string();
+        if enum_attrs.qualified {
+            ident_transformed = format!("{}::{}", enum_name, ident_transformed);
+            variant_name = format!("{}::{}", enum_name, variant_name);
+        }
+        if enum_attrs.ascii_only {
+            ensure_ascii(&ident_transformed, variant.ident.span())?;
+            ensure_ascii(&variant_name, variant.ident.span())?;
+            for lit_str in attrs.format.iter().chain(&attrs.join) {
+                ensure_ascii(&lit_str.value(), lit_str.span())?;
+            }
+        }
+        let attrs_quote = attrs.quote || enum_attrs.quote || enum_attrs.json;
+        // A `with` function writes straight into the formatter, so there's nothing to escape
+        if let (Some(with), true) = (&attrs.with, attrs_quote) {
+            return Err(syn::Error::new_spanned(
+                with,
+                "`with` can't be combined with `quote`",
+            ));
+        }
+        let has_arity_field = variant
+            .fields
+            .iter()
+            .any(|field| field.ident.as_ref().is_some_and(|ident| ident == "arity"));
+        let info = VariantInfo {
+            arity: (!has_arity_field).then(|| variant.fields.len()),
+            ident: variant.ident,
+            ident_transformed,
+            variant_name,
+            enum_name,
+            attrs,
+            prefix: enum_attrs.prefix.clone(),
+            suffix: enum_attrs.suffix.clone(),
+            pad: enum_attrs.pad,
+            quote: attrs_quote,
+            json: enum_attrs.json,
+            fast_numeric: enum_attrs.fast_numeric,
+            crate_path: enum_attrs.crate_path.clone(),
+            // Discriminants are `isize` unless the enum has an integer #[repr]
+            discriminant: discriminant.map(|value| {
+                let ty = match &enum_attrs.repr {
+                    Some(repr) => quote! { #repr },
+                    None => quote! { isize },
+                };
+                (ty, value)
+            }),
+        };
+
+        Ok(match variant.fields {
+            syn::Fields::Named(fields_named) => {
+                Self::Named(NamedVariantIR::from_fields_named(fields_named, info))
+            }
+            syn::Fields::Unnamed(fields_unnamed) => {
+                Self::Unnamed(UnnamedVariantIR::from_fields_unnamed(fields_unnamed, info))
+            }
+            syn::Fields::Unit => Self::Unit(UnitVariantIR { info }),
+        })
+    }
+
+    fn info(&self) -> &VariantInfo {
+        match self {
+            Self::Named(named) => &named.info,
+            Self::Unnamed(unnamed) => &unnamed.info,
+            Self::Unit(unit) => &unit.info,
+        }
+    }
+
+    // The type of every field the output interpolates, with the trait its placeholder needs
+    fn formatted_fields(&self) -> Vec<(&syn::Type, Ident)> {
+        let attrs = &self.info().attrs;
+        let types = match self {
+            Self::Named(variant) => &variant.types,
+            Self::Unnamed(variant) => &variant.types,
+            Self::Unit(_) => return Vec::new(),
+        };
+        if attrs.transparent.is_some() {
+            return vec![(&types[0], format_trait(""))];
+        }
+        let format = match &attrs.format {
+            Some(format) => format.value(),
+            None => return Vec::new(),
+        };
+
+        let aliases: &[Ident] = attrs
+            .field_aliases
+            .as_ref()
+            .map_or(&[], |(aliases, _)| aliases.as_slice());
+        placeholders(&format)
+            .into_iter()
+            .filter_map(|(arg, spec)| {
+                // A binding shadows any field of the same name, and its type isn't known
+                if attrs.bindings.iter().any(|(name, _)| *name == arg) {
+                    return None;
+                }
+                let index = match self {
+                    Self::Named(variant) => {
+                        let renamed = attrs
+                            .field_renames
+                            .iter()
+                            .find(|(alias, _)| *alias == arg)
+                            .map(|(_, field)| field.to_string());
+                        let name = renamed.as_deref().unwrap_or(&arg);
+                        arg.parse().ok().or_else(|| {
+                            variant
+                                .fields
+                                .iter()
+                                .position(|field| field.unraw() == name)
+                        })
                     }
+                    _ => arg
+                        .parse()
+                        .ok()
+                        .or_else(|| aliases.iter().position(|alias| *alias == arg))
+                        .or_else(|| (arg == "last").then(|| types.len() - 1)),
+                }?;
+                Some((types.get(index)?, format_trait(&spec)))
+            })
+            .collect()
+    }
+
+    // The type of every field, which `debug_alternate` writes with `{:?}`
+    fn alternate_fields(&self) -> Vec<(&syn::Type, Ident)> {
+        let types = match self {
+            Self::Named(variant) => &variant.types,
+            Self::Unnamed(variant) => &variant.types,
+            Self::Unit(_) => return Vec::new(),
+        };
+        types.iter().map(|ty| (ty, format_trait("?"))).collect()
+    }
+
+    // Whether this variant writes through `write!` rather than a plain name
+    fn has_format(&self) -> bool {
+        let attrs = &self.info().attrs;
+        attrs.format.is_some() || attrs.transparent.is_some() || attrs.with.is_some()
+    }
+
+    fn generate(&self, any_has_format: bool) -> TokenStream2 {
+        match self {
+            Self::Named(named) => named.generate(any_has_format),
+            Self::Unnamed(unnamed) => unnamed.generate(any_has_format),
+            Self::Unit(unit) => unit.generate(any_has_format),
+        }
+    }
+
+    // The match arm for `display_into`, which writes into `writer` directly wherever the output
+    // doesn't need a formatter. `transparent` and `with` hand the formatter to other code, so those
+    // variants still go through `Display`.
+    fn generate_into(&self, writer: &Ident) -> TokenStream2 {
+        let info = self.info();
+        let pattern = self.wildcard_pattern();
+        if info.attrs.transparent.is_some() || info.attrs.with.is_some() {
+            return quote! {
+                #pattern => ::core::fmt::Write::write_fmt(#writer, ::core::format_args!("{}", self)),
+            };
+        }
+        match (self, info.format_string()) {
+            (Self::Named(variant), Some(fmt)) => variant.formatted_arm(writer, fmt),
+            (Self::Unnamed(variant), Some(fmt)) => variant.formatted_arm(writer, fmt),
+            (Self::Unit(variant), Some(fmt)) => variant.formatted_arm(writer, fmt),
+            (_, None) => {
+                let display_name = info.display_name();
+                quote! {
+                    #pattern => ::core::fmt::Write::write_str(#writer, #display_name),
                 }
             }
         }
     }
 
-    // Build the match arms
-    let variants = match data {
-        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants,
-        _ => panic!("EnumDisplay can only be derived for enums"),
+    // The match arm for `{:#}` with `debug_alternate`, the variant's name followed by every field
+    // written with `Debug` like `debug_fields` would. It's written through the same affixes and
+    // adapting writers as the usual output, and a variant without fields is padded like a name.
+    fn generate_alternate(&self) -> TokenStream2 {
+        let info = self.info();
+        let ident = &info.ident;
+        let name = escape_braces(&info.variant_name);
+        let (pattern, fmt, fields) = match self {
+            Self::Named(variant) if !variant.fields.is_empty() => {
+                let fields = &variant.fields;
+                let values: Vec<String> = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        format!("{}: {{__enum_display_alternate_{}:?}}", field.unraw(), i)
+                    })
+                    .collect();
+                (
+                    quote! { #ident { #(#fields),* } },
+                    format!("{} {{{{ {} }}}}", name, values.join(", ")),
+                    fields.as_slice(),
+                )
+            }
+            Self::Unnamed(variant) if !variant.fields.is_empty() => {
+                let fields = &variant.fields;
+                let values: Vec<String> = (0..fields.len())
+                    .map(|i| format!("{{__enum_display_alternate_{}:?}}", i))
+                    .collect();
+                (
+                    quote! { #ident(#(#fields),*) },
+                    format!("{}({})", name, values.join(", ")),
+                    fields.as_slice(),
+                )
+            }
+            _ => {
+                let f = formatter();
+                let pattern = self.wildcard_pattern();
+                let write_name = info.write_name();
+                let output = info.quoted(info.cased(info.truncated(format!(
+                    "{}{}{}",
+                    info.prefix, info.variant_name, info.suffix
+                ))));
+                return quote! {
+                    #pattern => #write_name(#f, #output),
+                };
+            }
+        };
+        let bindings = fields.iter().enumerate().map(|(i, field)| {
+            let alternate = format_ident!("__enum_display_alternate_{}", i);
+            quote! { let #alternate = #field; }
+        });
+        let fmt = format!(
+            "{}{}{}",
+            escape_braces(&info.prefix),
+            fmt,
+            escape_braces(&info.suffix)
+        );
+        let body = info.write_formatted(&fmt, quote! { #(#bindings)* });
+        quote! {
+            #pattern => #body
+        }
     }
-    .into_iter()
-    .map(|variant| {
-        let ident = variant.ident;
-        let ident_str = if case_transform.is_some() {
-            ident.to_string().to_case(case_transform.unwrap())
-        } else {
-            ident.to_string()
+
+    // A pattern matching this variant without binding any of its fields
+    fn wildcard_pattern(&self) -> TokenStream2 {
+        let ident = &self.info().ident;
+        match self {
+            Self::Named(_) => quote! { #ident { .. } },
+            Self::Unnamed(_) => quote! { #ident(..) },
+            Self::Unit(_) => quote! { #ident },
+        }
+    }
+
+    // What `variant_name` returns, the transformed name stands in for output only known at runtime
+    fn static_name(&self) -> String {
+        let info = self.info();
+        info.static_display()
+            .unwrap_or_else(|| info.ident_transformed.clone())
+    }
+
+    // The match arm for `variant_name`, formatted variants that can't be known at expansion
+    // time fall back to their transformed name
+    fn generate_name(&self) -> TokenStream2 {
+        let pattern = self.wildcard_pattern();
+        let name = self.static_name();
+        quote! {
+            #pattern => #name,
+        }
+    }
+
+    // The match arm for `as_cstr`, or nothing when the name can't be a C string because it
+    // contains a nul byte. syn 1 can't parse `c"..."` literals, so the bytes are checked in a
+    // const instead, which keeps the conversion at compile time all the same.
+    fn generate_cstr(&self, crate_path: &syn::Path) -> Option<TokenStream2> {
+        let pattern = self.wildcard_pattern();
+        let name = std::ffi::CString::new(self.static_name()).ok()?;
+        let name = proc_macro2::Literal::byte_string(name.as_bytes_with_nul());
+        Some(quote! {
+            #pattern => {
+                const NAME: &::core::ffi::CStr = #crate_path::__private::cstr(#name);
+                NAME
+            }
+        })
+    }
+
+    // The match arm for `from_repr`, only unit variants can be built back from their discriminant
+    fn generate_from_repr(&self, path: &TokenStream2) -> Option<TokenStream2> {
+        let info = match self {
+            Self::Unit(unit) => &unit.info,
+            _ => return None,
         };
+        let ident = &info.ident;
+        let (_, value) = info.discriminant.as_ref()?;
+        Some(quote! {
+            value if value == (#value) => ::core::option::Option::Some(#path #ident),
+        })
+    }
 
-        match variant.fields {
-            syn::Fields::Named(_) => quote! {
-                #ident { .. } => #ident_str,
-            },
-            syn::Fields::Unnamed(_) => quote! {
-                #ident(..) => #ident_str,
+    // The match arm for `display_with`, giving the variant's name as it was declared
+    fn generate_base_name(&self) -> TokenStream2 {
+        let pattern = self.wildcard_pattern();
+        let name = self.info().ident.unraw().to_string();
+        quote! {
+            #pattern => #name,
+        }
+    }
+
+    // The match arm for `display_cow`, borrowing the output whenever it's known at expansion time
+    fn generate_cow(&self, crate_path: &syn::Path) -> TokenStream2 {
+        let pattern = self.wildcard_pattern();
+        match self.info().static_display() {
+            Some(name) => quote! {
+                #pattern => #crate_path::__private::Cow::Borrowed(#name),
             },
-            syn::Fields::Unit => quote! {
-                #ident => #ident_str,
+            None => quote! {
+                #pattern => #crate_path::__private::Cow::Owned(
+                    #crate_path::__private::ToString::to_string(self),
+                ),
             },
         }
+    }
+}
+
+// Build the intermediate representation of each variant
+fn parse_variants(
+    data: syn::Data,
+    enum_ident: &Ident,
+    enum_attrs: &EnumAttrs,
+    derive_name: &str,
+) -> syn::Result<Vec<VariantIR>> {
+    // `EnumDisplay` handles structs itself, so a struct only gets here for the parsing derives
+    let variants = match data {
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants,
+        syn::Data::Struct(syn::DataStruct { struct_token, .. }) => {
+            return Err(syn::Error::new_spanned(
+                struct_token,
+                format!(
+                    "{} can only be derived for enums, structs can only derive `EnumDisplay`",
+                    derive_name
+                ),
+            ))
+        }
+        syn::Data::Union(syn::DataUnion { union_token, .. }) => {
+            return Err(syn::Error::new_spanned(
+                union_token,
+                format!("{} can't be derived for unions", derive_name),
+            ))
+        }
+    };
+
+    // A variant without an explicit discriminant is one more than the variant before it, so its
+    // value is counted from the last explicit one, or from zero
+    let mut last_explicit: Option<syn::Expr> = None;
+    let mut offset: u128 = 0;
+    variants
+        .into_iter()
+        .map(|mut variant| {
+            if let Some((_, expr)) = variant.discriminant.take() {
+                last_explicit = Some(expr);
+                offset = 0;
+            }
+            let discriminant = implicit_discriminant(last_explicit.as_ref(), offset);
+            offset += 1;
+            VariantIR::from_variant(variant, Some(discriminant), enum_ident, enum_attrs)
+        })
+        .collect()
+}
+
+// The expression for a discriminant `offset` variants after `base`, literals are added up
+// at expansion time so the generated code stays readable
+fn implicit_discriminant(base: Option<&syn::Expr>, offset: u128) -> TokenStream2 {
+    let base = match base {
+        Some(base) => base,
+        None => {
+            let value = proc_macro2::Literal::u128_unsuffixed(offset);
+            return quote! { #value };
+        }
+    };
+    if offset == 0 {
+        return quote! { #base };
+    }
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(int),
+        ..
+    }) = base
+    {
+        if let Some(value) = int
+            .base10_parse::<u128>()
+            .ok()
+            .and_then(|value| value.checked_add(offset))
+        {
+            let value = proc_macro2::Literal::u128_unsuffixed(value);
+            return quote! { #value };
+        }
+    }
+    let offset = proc_macro2::Literal::u128_unsuffixed(offset);
+    quote! { (#base) + #offset }
+}
+
+fn expand_display(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let f = formatter();
+    let DeriveInput {
+        ident,
+        data,
+        attrs,
+        generics,
+        vis,
+    } = input;
+
+    let enum_attrs = EnumAttrs::from_attrs(attrs.clone())?;
+
+    // A struct displays like an enum with a single variant named after the struct, so its
+    // match arm is a plain struct pattern rather than a path through the enum
+    let (variants, path, is_struct) = match data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => {
+            // On a struct #[enum_display(...)] is the enum level attribute, not the variant's
+            let attrs = attrs
+                .into_iter()
+                .filter(|attr| !attr.path.is_ident("enum_display"))
+                .collect();
+            let variant = Variant {
+                attrs,
+                ident: ident.clone(),
+                fields,
+                discriminant: None,
+            };
+            let variant = VariantIR::from_variant(variant, None, &ident, &enum_attrs)?;
+            (vec![variant], quote! {}, true)
+        }
+        data => {
+            let variants = parse_variants(data, &ident, &enum_attrs, "EnumDisplay")?;
+            (variants, quote! { #ident:: }, false)
+        }
+    };
+
+    if enum_attrs.unique {
+        check_unique(&variants)?;
+    }
+
+    // Formatted variants write straight into the formatter, so once any variant
+    // has a format string every match arm has to produce a `fmt::Result`
+    let any_has_format = variants.iter().any(VariantIR::has_format);
+
+    // Fields whose type depends on a type parameter need their formatting trait as a bound,
+    // so the enum itself doesn't have to declare bounds only the Display impl cares about
+    let mut bounded = generics.clone();
+    let params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let mut predicates: Vec<TokenStream2> = Vec::new();
+    let alternate_fields = variants
+        .iter()
+        .filter(|_| enum_attrs.debug_alternate)
+        .flat_map(VariantIR::alternate_fields);
+    for (ty, format_trait) in variants
+        .iter()
+        .flat_map(VariantIR::formatted_fields)
+        .chain(alternate_fields)
+    {
+        let ty = strip_references(ty);
+        let predicate = quote! { #ty: ::core::fmt::#format_trait };
+        if mentions_params(quote! { #ty }, &params)
+            && !predicates
+                .iter()
+                .any(|existing| existing.to_string() == predicate.to_string())
+        {
+            predicates.push(predicate);
+        }
+    }
+    let where_clause = bounded.make_where_clause();
+    for predicate in predicates {
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #predicate });
+    }
+    let (impl_generics, ty_generics, where_clause) = bounded.split_for_impl();
+
+    // Build the match arms
+    let names = variants.iter().map(VariantIR::generate_name);
+    let display_names = variants.iter().map(VariantIR::static_name);
+    let indices = variants.iter().enumerate().map(|(index, variant)| {
+        let pattern = variant.wildcard_pattern();
+        quote! {
+            #path #pattern => #index,
+        }
     });
 
+    // `coverage(off)` is still unstable, so it's only applied under the `coverage_nightly` cfg
+    // that cargo-llvm-cov sets when it runs on nightly
+    let no_coverage = if enum_attrs.no_coverage {
+        quote! { #[cfg_attr(coverage_nightly, coverage(off))] }
+    } else {
+        quote! {}
+    };
+
+    // `display_cow` needs `alloc`, so it's wrapped in a macro from the facade crate that only
+    // keeps it when the facade's `alloc` feature is enabled
+    let crate_path = &enum_attrs.crate_path;
+    let cow_arms = variants
+        .iter()
+        .map(|variant| variant.generate_cow(crate_path));
+    let base_names = variants.iter().map(VariantIR::generate_base_name);
+    // An empty enum has no values, matching on the value itself with no arms is what makes every
+    // body exhaustive. Anything written after that match would be unreachable, so it's left out.
+    let is_empty = variants.is_empty();
+    let display_with = if is_empty {
+        quote! {
+            let _ = overrides;
+            match *self {}
+        }
+    } else {
+        quote! {
+            let name = match self {
+                #(#path #base_names)*
+            };
+            overrides(name).unwrap_or_else(|| #crate_path::__private::ToString::to_string(self))
+        }
+    };
+    let scrutinee = if is_empty {
+        quote! { *self }
+    } else {
+        quote! { self }
+    };
+    let display_cow = quote! {
+        #crate_path::__if_alloc! {
+            /// The string this variant displays as, only allocating when its output includes fields.
+            #no_coverage
+            #vis fn display_cow(&self) -> #crate_path::__private::Cow<'static, str> {
+                match #scrutinee {
+                    #(#path #cow_arms)*
+                }
+            }
+
+            /// The string this variant displays as, unless `overrides` returns a replacement when
+            /// called with the variant's declared name.
+            #no_coverage
+            #vis fn display_with(
+                &self,
+                overrides: &dyn ::core::ops::Fn(&str) -> ::core::option::Option<#crate_path::__private::String>,
+            ) -> #crate_path::__private::String {
+                #display_with
+            }
+        }
+    };
+    let arms = variants
+        .iter()
+        .map(|variant| variant.generate(any_has_format));
+
+    // Formatted variants leave the layout to their own format string, plain names are written
+    // as is unless the enum opts into padding with #[enum_display(pad)]
+    let write_name = write_name(enum_attrs.pad);
+    let alternate = if enum_attrs.debug_alternate && !is_empty {
+        let alternate_arms = variants.iter().map(VariantIR::generate_alternate);
+        quote! {
+            if ::core::fmt::Formatter::alternate(#f) {
+                return match self {
+                    #(#path #alternate_arms)*
+                };
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // `display_into` writes with `write!` like `fmt` does, which needs the trait in scope for a
+    // generic writer
+    let buf = buffer();
+    let into_arms = variants.iter().map(|variant| variant.generate_into(&buf));
+    let into_body = if is_empty {
+        quote! {
+            match *self {}
+        }
+    } else {
+        quote! {
+            use ::core::fmt::Write as _;
+            match self {
+                #(#path #into_arms)*
+            }
+        }
+    };
+    let body = if is_empty {
+        quote! {
+            match *self {}
+        }
+    } else if any_has_format {
+        quote! {
+            match self {
+                #(#path #arms)*
+            }
+        }
+    } else {
+        quote! {
+            #write_name(
+                #f,
+                match self {
+                    #(#path #arms)*
+                },
+            )
+        }
+    };
+
+    // A plain match over names is trivial enough to always inline, formatted variants can
+    // expand to a lot of code so they're only inlined when asked for
+    let inline = if !any_has_format || enum_attrs.inline {
+        quote! { #[inline] }
+    } else {
+        quote! {}
+    };
+
+    let debug = if enum_attrs.debug {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+                #[inline]
+                #no_coverage
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(self, f)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Only unit variants are always written as a static name, so only enums of nothing but unit
+    // variants get their names as C strings
+    let cstr_arms: Option<Vec<TokenStream2>> = variants
+        .iter()
+        .map(|variant| match variant {
+            VariantIR::Unit(_) => variant.generate_cstr(crate_path),
+            _ => None,
+        })
+        .collect();
+    let as_cstr = match cstr_arms {
+        Some(cstr_arms) => quote! {
+            /// The name this variant is displayed as, as a nul-terminated C string.
+            #no_coverage
+            #vis const fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+                match #scrutinee {
+                    #(#path #cstr_arms)*
+                }
+            }
+        },
+        None => quote! {},
+    };
+
+    // An enum with an integer #[repr] can be built back from the numbers its unit variants stand for
+    let from_repr = match &enum_attrs.repr {
+        Some(repr) if !is_struct => {
+            let repr_arms = variants
+                .iter()
+                .filter_map(|variant| variant.generate_from_repr(&path));
+            quote! {
+                /// The unit variant whose discriminant is `value`, if there is one.
+                #no_coverage
+                #vis const fn from_repr(value: #repr) -> ::core::option::Option<Self> {
+                    match value {
+                        #(#repr_arms)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    // Only an enum of unit variants has a value for every variant without making up fields
+    let all_unit = variants
+        .iter()
+        .all(|variant| matches!(variant, VariantIR::Unit(_)));
+    let iter = if all_unit && !is_struct {
+        let idents = variants.iter().map(|variant| &variant.info().ident);
+        quote! {
+            /// Every variant in declaration order.
+            #no_coverage
+            #vis fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+                [#(#path #idents),*].into_iter()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // The other inherent helpers only make sense for enums
+    let enum_helpers = if is_struct {
+        quote! {}
+    } else {
+        quote! {
+                /// The name this variant is displayed as, without any interpolated fields.
+                #no_coverage
+                #vis const fn variant_name(&self) -> &'static str {
+                    match #scrutinee {
+                        #(#path #names)*
+                    }
+                }
+
+                /// The names of every variant in declaration order, as returned by `variant_name`.
+                #no_coverage
+                #vis const fn display_names() -> &'static [&'static str] {
+                    &[#(#display_names),*]
+                }
+
+                /// The position this variant is declared at, starting from zero and regardless
+                /// of any explicit discriminant.
+                #no_coverage
+                #vis const fn variant_index(&self) -> usize {
+                    match #scrutinee {
+                        #(#indices)*
+                    }
+                }
+
+                #as_cstr
+
+                #from_repr
+
+                #iter
+
+                #display_cow
+        }
+    };
+
+    // `Display` only forwards to `write_display`, which can be called without the trait in scope.
+    // The matches name every variant, #[allow(deprecated)] keeps deprecated ones from warning
+    // about code the user didn't write, the same goes for the parsing impls. The helpers are as
+    // visible as the type, so on a private one most of them are never called.
+    let helpers = quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        #[allow(deprecated)]
+        #[allow(dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Writes the value the way its `Display` implementation does.
+            #inline
+            #no_coverage
+            #[allow(unused_variables)]
+            #vis fn write_display(&self, #f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #alternate
+                #body
+            }
+
+            /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+            /// such as a fixed size buffer, without allocating.
+            #inline
+            #no_coverage
+            #[allow(unused_variables)]
+            #vis fn display_into(&self, #buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+                #into_body
+            }
+
+            #enum_helpers
+        }
+    };
+
+    let require_fast_numeric = if enum_attrs.fast_numeric {
+        quote! { #crate_path::__require_fast_numeric!(); }
+    } else {
+        quote! {}
+    };
+
     // #[allow(unused_qualifications)] is needed
     // due to https://github.com/SeedyROM/enum-display/issues/1
     // Possibly related to https://github.com/rust-lang/rust/issues/96698
-    let output = quote! {
+    Ok(quote! {
         #[automatically_derived]
         #[allow(unused_qualifications)]
-        impl ::core::fmt::Display for #ident {
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            #[inline]
+            #no_coverage
             fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                ::core::fmt::Formatter::write_str(
-                    f,
-                    match self {
-                        #(#ident::#variants)*
-                    },
+                self.write_display(f)
+            }
+        }
+
+        #debug
+
+        #helpers
+
+        #require_fast_numeric
+    })
+}
+
+// Two variants with the same fixed output can't be told apart by it, which breaks parsing it
+// back. Output that interpolates fields isn't known until runtime, so it isn't compared.
+fn check_unique(variants: &[VariantIR]) -> syn::Result<()> {
+    let mut seen: Vec<(String, &Ident)> = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    for variant in variants {
+        let ident = &variant.info().ident;
+        let display = match variant.info().static_display() {
+            Some(display) => display,
+            None => continue,
+        };
+        match seen.iter().find(|(other, _)| *other == display) {
+            Some((_, other)) => {
+                let mut error = syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "`{}` displays as `{}`, the same as `{}`",
+                        ident, display, other
+                    ),
+                );
+                error.combine(syn::Error::new_spanned(
+                    other,
+                    format!("`{}` is declared here", other),
+                ));
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+            }
+            None => seen.push((display, ident)),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[proc_macro_derive(EnumDisplay, attributes(enum_display, display))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let input = parse_macro_input!(input);
+    expand_display(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// Build a match on `s` against exactly the strings the Display impl would produce, only unit
+// variants with a fixed output can be parsed back. With `parse_case_insensitive` both sides are
+// compared lowercased, so two variants that only differ in case can't be told apart.
+fn generate_parse_body(
+    ident: &Ident,
+    variants: &[VariantIR],
+    derive_name: &str,
+    enum_attrs: &EnumAttrs,
+) -> syn::Result<TokenStream2> {
+    let crate_path = &enum_attrs.crate_path;
+    let mut lowercased: Vec<(String, &Ident)> = Vec::new();
+    let arms = variants
+        .iter()
+        .map(|variant| {
+            let info = variant.info();
+            let variant_ident = &info.ident;
+            if !matches!(variant, VariantIR::Unit(_)) {
+                return Err(syn::Error::new_spanned(
+                    variant_ident,
+                    format!(
+                        "{} can only be derived for enums with unit variants, `{}` has fields",
+                        derive_name, variant_ident
+                    ),
+                ));
+            }
+            let display = info.static_display().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    variant_ident,
+                    format!(
+                        "{} can't parse `{}` because its format string isn't a fixed string",
+                        derive_name, variant_ident
+                    ),
                 )
+            })?;
+            if !enum_attrs.parse_case_insensitive {
+                return Ok(quote! {
+                    #display => ::core::result::Result::Ok(#ident::#variant_ident),
+                });
+            }
+            // Lowercased a char at a time like the input is, `str::to_lowercase` would treat a
+            // final sigma differently
+            let lowercase: String = display.chars().flat_map(char::to_lowercase).collect();
+            if let Some((_, other)) = lowercased.iter().find(|(other, _)| *other == lowercase) {
+                return Err(syn::Error::new_spanned(
+                    variant_ident,
+                    format!(
+                        "`{}` and `{}` both parse from `{}` when case is ignored",
+                        other, variant_ident, lowercase
+                    ),
+                ));
+            }
+            lowercased.push((lowercase.clone(), variant_ident));
+            Ok(quote! {
+                _ if #crate_path::__private::eq_lowercase(s, #lowercase) => {
+                    ::core::result::Result::Ok(#ident::#variant_ident)
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        match s {
+            #(#arms)*
+            _ => ::core::result::Result::Err(#crate_path::ParseEnumError::new(s)),
+        }
+    })
+}
+
+fn expand_from_str(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        ident,
+        data,
+        attrs,
+        vis,
+        ..
+    } = input;
+
+    let enum_attrs = EnumAttrs::from_attrs(attrs)?;
+    let variants = parse_variants(data, &ident, &enum_attrs, "EnumFromStr")?;
+    let crate_path = &enum_attrs.crate_path;
+    let body = generate_parse_body(&ident, &variants, "EnumFromStr", &enum_attrs)?;
+    let or_default = generate_parse_or_default(&ident, &vis, &variants)?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        #[allow(deprecated)]
+        impl ::core::str::FromStr for #ident {
+            type Err = #crate_path::ParseEnumError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #body
             }
         }
+
+        #or_default
+    })
+}
+
+// `from_str_or_default`, for enums with a variant marked #[display(default)]
+fn generate_parse_or_default(
+    ident: &Ident,
+    vis: &syn::Visibility,
+    variants: &[VariantIR],
+) -> syn::Result<TokenStream2> {
+    let mut defaults = variants
+        .iter()
+        .filter_map(|variant| Some((variant, variant.info().attrs.default?)));
+    let (variant, _) = match defaults.next() {
+        Some(default) => default,
+        None => return Ok(quote! {}),
     };
-    output.into()
+    if let Some((_, span)) = defaults.next() {
+        return Err(syn::Error::new(
+            span,
+            "only one variant can be marked `default`",
+        ));
+    }
+    // Parsing only produces unit variants, so the default can't have fields either
+    let variant_ident = &variant.info().ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        #[allow(deprecated)]
+        #[allow(dead_code)]
+        impl #ident {
+            /// Parses `s` like `from_str`, falling back to the `default` variant when it doesn't
+            /// match any variant.
+            #vis fn from_str_or_default(s: &str) -> Self {
+                ::core::str::FromStr::from_str(s).unwrap_or(#ident::#variant_ident)
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(EnumFromStr, attributes(enum_display, display))]
+pub fn derive_from_str(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let input = parse_macro_input!(input);
+    expand_from_str(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_try_from(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = input;
+
+    let enum_attrs = EnumAttrs::from_attrs(attrs)?;
+    let variants = parse_variants(data, &ident, &enum_attrs, "EnumTryFrom")?;
+    let crate_path = &enum_attrs.crate_path;
+    let body = generate_parse_body(&ident, &variants, "EnumTryFrom", &enum_attrs)?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<&str> for #ident {
+            type Error = #crate_path::ParseEnumError;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                #body
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(EnumTryFrom, attributes(enum_display, display))]
+pub fn derive_try_from(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a syntax tree
+    let input = parse_macro_input!(input);
+    expand_try_from(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    // Expand every derive listed on the item, the way the compiler would call them
+    fn expand(input: DeriveInput) -> TokenStream2 {
+        let mut derives = Vec::new();
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("derive"))
+        {
+            let paths = attr
+                .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .unwrap();
+            derives.extend(paths);
+        }
+
+        let mut output = TokenStream2::new();
+        for derive in derives {
+            let expanded = if derive.is_ident("EnumDisplay") {
+                expand_display(input.clone())
+            } else if derive.is_ident("EnumFromStr") {
+                expand_from_str(input.clone())
+            } else if derive.is_ident("EnumTryFrom") {
+                expand_try_from(input.clone())
+            } else {
+                panic!("unknown derive `{}`", derive.get_ident().unwrap());
+            };
+            output.extend(expanded.unwrap_or_else(syn::Error::into_compile_error));
+        }
+        output
+    }
+
+    fn unnamed_fields(count: usize) -> Vec<Ident> {
+        (0..count)
+            .map(|i| format_ident!("_unnamed_{}", i))
+            .collect()
+    }
+
+    #[test]
+    fn numeric_placeholders_need_a_spec_boundary() {
+        let fields = unnamed_fields(4);
+        assert_eq!(
+            translate_numeric_placeholders("{ 0 :#x}{1}{{2}}{3abc}", &fields),
+            "{_unnamed_0:#x}{_unnamed_1}{{2}}{3abc}"
+        );
+        assert_eq!(
+            translate_numeric_placeholders("{0:?} {1:#?} { 2 :?}", &fields),
+            "{_unnamed_0:?} {_unnamed_1:#?} {_unnamed_2:?}"
+        );
+    }
+
+    #[test]
+    fn numeric_widths_and_precisions_are_translated() {
+        let fields = unnamed_fields(3);
+        assert_eq!(
+            translate_numeric_placeholders("{0:1$} {2:>0$.1$} {0:05$}", &fields),
+            "{_unnamed_0:_unnamed_1$} {_unnamed_2:>_unnamed_0$._unnamed_1$} {_unnamed_0:05$}"
+        );
+        assert_eq!(
+            translate_numeric_placeholders("{0:02$} {{0:1$}} {name:1$}", &fields),
+            "{_unnamed_0:0_unnamed_2$} {{0:1$}} {name:_unnamed_1$}"
+        );
+    }
+
+    // The regexes are shared between calls, so translating one string mustn't affect the next
+    #[test]
+    fn shared_regexes_translate_every_string_the_same() {
+        for _ in 0..3 {
+            assert_eq!(
+                translate_numeric_placeholders("{0} {{1}} {2:>4}", &unnamed_fields(3)),
+                "{_unnamed_0} {{1}} {_unnamed_2:>4}"
+            );
+            assert_eq!(
+                translate_name_placeholders("{variant}: {enum:?}"),
+                "{__enum_display_variant}: {__enum_display_enum:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn format_syntax_accounts_for_escapes() {
+        assert!(check_format_syntax("{{{variant}}} {{0}} {0:>4} { 1 :#x} {}").is_ok());
+        assert!(check_format_syntax("{{0}").is_err());
+        assert!(check_format_syntax("{0}}}}").is_err());
+        assert!(check_format_syntax("{{{0}").is_ok());
+    }
+
+    // Compare the expansion of each tests/expand/*.rs against its .expanded.rs snapshot, run with
+    // EXPAND=overwrite to update the snapshots after changing the generated code
+    #[test]
+    fn expand_snapshots() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+        let overwrite = std::env::var("EXPAND").as_deref() == Ok("overwrite");
+        let mut inputs: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.to_string_lossy();
+                name.ends_with(".rs") && !name.ends_with(".expanded.rs")
+            })
+            .collect();
+        inputs.sort();
+        assert!(!inputs.is_empty());
+
+        let mut mismatches = Vec::new();
+        for input_path in inputs {
+            let input = syn::parse_str(&fs::read_to_string(&input_path).unwrap()).unwrap();
+            let expanded = prettyplease::unparse(&syn::parse2(expand(input)).unwrap());
+            let snapshot_path = input_path.with_extension("expanded.rs");
+            if overwrite {
+                fs::write(&snapshot_path, expanded).unwrap();
+            } else if fs::read_to_string(&snapshot_path).ok().as_ref() != Some(&expanded) {
+                mismatches.push(snapshot_path.display().to_string());
+            }
+        }
+        assert!(
+            mismatches.is_empty(),
+            "expansions changed, rerun with EXPAND=overwrite to update: {}",
+            mismatches.join(", ")
+        );
+    }
 }
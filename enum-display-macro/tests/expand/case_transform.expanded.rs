@@ -0,0 +1,105 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for Header {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl Header {
+    /// Writes the value the way its `Display` implementation does.
+    #[inline]
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                Header::ContentType => "[content-type]",
+                Header::MaxAge => "[MAX_AGE]",
+            },
+        )
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[inline]
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        use ::core::fmt::Write as _;
+        match self {
+            Header::ContentType => ::core::fmt::Write::write_str(buf, "[content-type]"),
+            Header::MaxAge => ::core::fmt::Write::write_str(buf, "[MAX_AGE]"),
+        }
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Header::ContentType => "[content-type]",
+            Header::MaxAge => "[MAX_AGE]",
+        }
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &["[content-type]", "[MAX_AGE]"]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match self {
+            Header::ContentType => 0usize,
+            Header::MaxAge => 1usize,
+        }
+    }
+    /// The name this variant is displayed as, as a nul-terminated C string.
+    const fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+        match self {
+            Header::ContentType => {
+                const NAME: &::core::ffi::CStr = ::enum_display::__private::cstr(
+                    b"[content-type]\0",
+                );
+                NAME
+            }
+            Header::MaxAge => {
+                const NAME: &::core::ffi::CStr = ::enum_display::__private::cstr(
+                    b"[MAX_AGE]\0",
+                );
+                NAME
+            }
+        }
+    }
+    /// Every variant in declaration order.
+    fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        [Header::ContentType, Header::MaxAge].into_iter()
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        self { Header::ContentType =>
+        ::enum_display::__private::Cow::Borrowed("[content-type]"), Header::MaxAge =>
+        ::enum_display::__private::Cow::Borrowed("[MAX_AGE]"), } } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        name = match self { Header::ContentType => "ContentType", Header::MaxAge =>
+        "MaxAge", }; overrides(name).unwrap_or_else(||
+        ::enum_display::__private::ToString::to_string(self)) }
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+impl ::core::str::FromStr for Header {
+    type Err = ::enum_display::ParseEnumError;
+    fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+        match s {
+            "[content-type]" => ::core::result::Result::Ok(Header::ContentType),
+            "[MAX_AGE]" => ::core::result::Result::Ok(Header::MaxAge),
+            _ => ::core::result::Result::Err(::enum_display::ParseEnumError::new(s)),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(EnumDisplay, EnumFromStr)]
+#[enum_display(case = "Kebab", prefix = "[", suffix = "]")]
+enum Header {
+    ContentType,
+    #[display(case = "ScreamingSnake")]
+    MaxAge,
+}
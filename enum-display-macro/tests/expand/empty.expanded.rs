@@ -0,0 +1,59 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for Never {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl Never {
+    /// Writes the value the way its `Display` implementation does.
+    #[inline]
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match *self {}
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[inline]
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        match *self {}
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match *self {}
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &[]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match *self {}
+    }
+    /// The name this variant is displayed as, as a nul-terminated C string.
+    const fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+        match *self {}
+    }
+    /// Every variant in declaration order.
+    fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        [].into_iter()
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        * self {} } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        _ = overrides; match * self {} }
+    }
+}
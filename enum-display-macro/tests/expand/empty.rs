@@ -0,0 +1,2 @@
+#[derive(EnumDisplay)]
+enum Never {}
@@ -0,0 +1,104 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for Sample {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl Sample {
+    /// Writes the value the way its `Display` implementation does.
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Sample::Point(_unnamed_0, _unnamed_1) => {
+                ::core::fmt::Formatter::write_str(f, "Point(")?;
+                ::enum_display::__private::write_integer(f, *_unnamed_0)?;
+                ::core::fmt::Formatter::write_str(f, ", ")?;
+                ::enum_display::__private::write_integer(f, *_unnamed_1)?;
+                ::core::fmt::Formatter::write_str(f, ")")?;
+                ::core::result::Result::Ok(())
+            }
+            Sample::Ratio(_unnamed_0) => {
+                let __enum_display_variant = "Ratio";
+                let __enum_display_enum = "Sample";
+                ::core::write!(f, "{_unnamed_0}")
+            }
+            Sample::Padded(_unnamed_0) => {
+                let __enum_display_variant = "Padded";
+                let __enum_display_enum = "Sample";
+                ::core::write!(f, "{_unnamed_0:>4}")
+            }
+        }
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        use ::core::fmt::Write as _;
+        match self {
+            Sample::Point(_unnamed_0, _unnamed_1) => {
+                let __enum_display_variant = "Point";
+                let __enum_display_enum = "Sample";
+                ::core::write!(
+                    buf, "{__enum_display_variant}({_unnamed_0}, {_unnamed_1})"
+                )
+            }
+            Sample::Ratio(_unnamed_0) => {
+                let __enum_display_variant = "Ratio";
+                let __enum_display_enum = "Sample";
+                ::core::write!(buf, "{_unnamed_0}")
+            }
+            Sample::Padded(_unnamed_0) => {
+                let __enum_display_variant = "Padded";
+                let __enum_display_enum = "Sample";
+                ::core::write!(buf, "{_unnamed_0:>4}")
+            }
+        }
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Sample::Point(..) => "Point",
+            Sample::Ratio(..) => "Ratio",
+            Sample::Padded(..) => "Padded",
+        }
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &["Point", "Ratio", "Padded"]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match self {
+            Sample::Point(..) => 0usize,
+            Sample::Ratio(..) => 1usize,
+            Sample::Padded(..) => 2usize,
+        }
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        self { Sample::Point(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Sample::Ratio(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Sample::Padded(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        } } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        name = match self { Sample::Point(..) => "Point", Sample::Ratio(..) => "Ratio",
+        Sample::Padded(..) => "Padded", }; overrides(name).unwrap_or_else(||
+        ::enum_display::__private::ToString::to_string(self)) }
+    }
+}
+::enum_display::__require_fast_numeric!();
@@ -0,0 +1,10 @@
+#[derive(EnumDisplay)]
+#[enum_display(fast_numeric)]
+enum Sample {
+    #[display("{variant}({0}, {1})")]
+    Point(u64, i8),
+    #[display("{0}")]
+    Ratio(f32),
+    #[display("{0:>4}")]
+    Padded(u32),
+}
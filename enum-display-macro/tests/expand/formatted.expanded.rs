@@ -0,0 +1,122 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for Message {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl Message {
+    /// Writes the value the way its `Display` implementation does.
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Message::Text { text } => {
+                let __enum_display_variant = "Text";
+                let __enum_display_enum = "Message";
+                ::core::write!(f, "{__enum_display_variant}: {text}")
+            }
+            Message::Code(_unnamed_0) => {
+                let __enum_display_variant = "Code";
+                let __enum_display_enum = "Message";
+                ::core::write!(
+                    f, "{__enum_display_enum}::{__enum_display_variant}({_unnamed_0:>4})"
+                )
+            }
+            Message::Location(_unnamed_0, _unnamed_1) => {
+                let __enum_display_variant = "Location";
+                let __enum_display_enum = "Message";
+                let lat = _unnamed_0;
+                let lon = _unnamed_1;
+                let sum = lat + lon;
+                ::core::write!(f, "{lat},{lon} ({sum})")
+            }
+            Message::Inner(_unnamed_0) => ::core::fmt::Display::fmt(_unnamed_0, f),
+            Message::Empty => ::core::fmt::Formatter::write_str(f, "Empty"),
+        }
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        use ::core::fmt::Write as _;
+        match self {
+            Message::Text { text } => {
+                let __enum_display_variant = "Text";
+                let __enum_display_enum = "Message";
+                ::core::write!(buf, "{__enum_display_variant}: {text}")
+            }
+            Message::Code(_unnamed_0) => {
+                let __enum_display_variant = "Code";
+                let __enum_display_enum = "Message";
+                ::core::write!(
+                    buf,
+                    "{__enum_display_enum}::{__enum_display_variant}({_unnamed_0:>4})"
+                )
+            }
+            Message::Location(_unnamed_0, _unnamed_1) => {
+                let __enum_display_variant = "Location";
+                let __enum_display_enum = "Message";
+                let lat = _unnamed_0;
+                let lon = _unnamed_1;
+                let sum = lat + lon;
+                ::core::write!(buf, "{lat},{lon} ({sum})")
+            }
+            Message::Inner(..) => {
+                ::core::fmt::Write::write_fmt(buf, ::core::format_args!("{}", self))
+            }
+            Message::Empty => ::core::fmt::Write::write_str(buf, "Empty"),
+        }
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Message::Text { .. } => "Text",
+            Message::Code(..) => "Code",
+            Message::Location(..) => "Location",
+            Message::Inner(..) => "Inner",
+            Message::Empty => "Empty",
+        }
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &["Text", "Code", "Location", "Inner", "Empty"]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match self {
+            Message::Text { .. } => 0usize,
+            Message::Code(..) => 1usize,
+            Message::Location(..) => 2usize,
+            Message::Inner(..) => 3usize,
+            Message::Empty => 4usize,
+        }
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        self { Message::Text { .. } =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Message::Code(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Message::Location(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Message::Inner(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Message::Empty => ::enum_display::__private::Cow::Borrowed("Empty"), } } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        name = match self { Message::Text { .. } => "Text", Message::Code(..) => "Code",
+        Message::Location(..) => "Location", Message::Inner(..) => "Inner",
+        Message::Empty => "Empty", }; overrides(name).unwrap_or_else(||
+        ::enum_display::__private::ToString::to_string(self)) }
+    }
+}
@@ -0,0 +1,12 @@
+#[derive(EnumDisplay)]
+enum Message {
+    #[display("{variant}: {text}")]
+    Text { text: String },
+    #[display("{enum}::{variant}({0:>4})")]
+    Code(u32),
+    #[display(fields(lat, lon), bind(sum = "lat + lon"), "{lat},{lon} ({sum})")]
+    Location(f64, f64),
+    #[display(transparent)]
+    Inner(u8),
+    Empty,
+}
@@ -0,0 +1,92 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl<L, R> ::core::fmt::Display for Either<L, R>
+where
+    L: ::core::fmt::Display,
+    Vec<R>: ::core::fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl<L, R> Either<L, R>
+where
+    L: ::core::fmt::Display,
+    Vec<R>: ::core::fmt::Debug,
+{
+    /// Writes the value the way its `Display` implementation does.
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Either::Left(_unnamed_0) => {
+                let __enum_display_variant = "Left";
+                let __enum_display_enum = "Either";
+                ::core::write!(f, "left {_unnamed_0}")
+            }
+            Either::Right { value } => {
+                let __enum_display_variant = "Right";
+                let __enum_display_enum = "Either";
+                ::core::write!(f, "right {value:?}")
+            }
+        }
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        use ::core::fmt::Write as _;
+        match self {
+            Either::Left(_unnamed_0) => {
+                let __enum_display_variant = "Left";
+                let __enum_display_enum = "Either";
+                ::core::write!(buf, "left {_unnamed_0}")
+            }
+            Either::Right { value } => {
+                let __enum_display_variant = "Right";
+                let __enum_display_enum = "Either";
+                ::core::write!(buf, "right {value:?}")
+            }
+        }
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Either::Left(..) => "Left",
+            Either::Right { .. } => "Right",
+        }
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &["Left", "Right"]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match self {
+            Either::Left(..) => 0usize,
+            Either::Right { .. } => 1usize,
+        }
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        self { Either::Left(..) =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        Either::Right { .. } =>
+        ::enum_display::__private::Cow::Owned(::enum_display::__private::ToString::to_string(self),),
+        } } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        name = match self { Either::Left(..) => "Left", Either::Right { .. } => "Right",
+        }; overrides(name).unwrap_or_else(||
+        ::enum_display::__private::ToString::to_string(self)) }
+    }
+}
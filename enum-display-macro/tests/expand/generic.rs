@@ -0,0 +1,7 @@
+#[derive(EnumDisplay)]
+enum Either<L, R> {
+    #[display("left {0}")]
+    Left(L),
+    #[display("right {value:?}")]
+    Right { value: Vec<R> },
+}
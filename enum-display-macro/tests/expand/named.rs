@@ -0,0 +1,5 @@
+#[derive(EnumDisplay)]
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+}
@@ -0,0 +1,102 @@
+#[automatically_derived]
+#[allow(unused_qualifications)]
+impl ::core::fmt::Display for Color {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        self.write_display(f)
+    }
+}
+#[automatically_derived]
+#[allow(unused_qualifications)]
+#[allow(deprecated)]
+#[allow(dead_code)]
+impl Color {
+    /// Writes the value the way its `Display` implementation does.
+    #[inline]
+    #[allow(unused_variables)]
+    fn write_display(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                Color::Red => "Red",
+                Color::Green => "Green",
+                Color::Blue => "Blue",
+            },
+        )
+    }
+    /// Writes the value the way its `Display` implementation does into any `fmt::Write`,
+    /// such as a fixed size buffer, without allocating.
+    #[inline]
+    #[allow(unused_variables)]
+    fn display_into(&self, buf: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+        use ::core::fmt::Write as _;
+        match self {
+            Color::Red => ::core::fmt::Write::write_str(buf, "Red"),
+            Color::Green => ::core::fmt::Write::write_str(buf, "Green"),
+            Color::Blue => ::core::fmt::Write::write_str(buf, "Blue"),
+        }
+    }
+    /// The name this variant is displayed as, without any interpolated fields.
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Color::Red => "Red",
+            Color::Green => "Green",
+            Color::Blue => "Blue",
+        }
+    }
+    /// The names of every variant in declaration order, as returned by `variant_name`.
+    const fn display_names() -> &'static [&'static str] {
+        &["Red", "Green", "Blue"]
+    }
+    /// The position this variant is declared at, starting from zero and regardless
+    /// of any explicit discriminant.
+    const fn variant_index(&self) -> usize {
+        match self {
+            Color::Red => 0usize,
+            Color::Green => 1usize,
+            Color::Blue => 2usize,
+        }
+    }
+    /// The name this variant is displayed as, as a nul-terminated C string.
+    const fn as_cstr(&self) -> &'static ::core::ffi::CStr {
+        match self {
+            Color::Red => {
+                const NAME: &::core::ffi::CStr = ::enum_display::__private::cstr(
+                    b"Red\0",
+                );
+                NAME
+            }
+            Color::Green => {
+                const NAME: &::core::ffi::CStr = ::enum_display::__private::cstr(
+                    b"Green\0",
+                );
+                NAME
+            }
+            Color::Blue => {
+                const NAME: &::core::ffi::CStr = ::enum_display::__private::cstr(
+                    b"Blue\0",
+                );
+                NAME
+            }
+        }
+    }
+    /// Every variant in declaration order.
+    fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+        [Color::Red, Color::Green, Color::Blue].into_iter()
+    }
+    ::enum_display::__if_alloc! {
+        #[doc =
+        r" The string this variant displays as, only allocating when its output includes fields."]
+        fn display_cow(& self) -> ::enum_display::__private::Cow < 'static, str > { match
+        self { Color::Red => ::enum_display::__private::Cow::Borrowed("Red"),
+        Color::Green => ::enum_display::__private::Cow::Borrowed("Green"), Color::Blue =>
+        ::enum_display::__private::Cow::Borrowed("Blue"), } } #[doc =
+        r" The string this variant displays as, unless `overrides` returns a replacement when"]
+        #[doc = r" called with the variant's declared name."] fn display_with(& self,
+        overrides : & dyn::core::ops::Fn(& str) -> ::core::option::Option <
+        ::enum_display::__private::String >,) -> ::enum_display::__private::String { let
+        name = match self { Color::Red => "Red", Color::Green => "Green", Color::Blue =>
+        "Blue", }; overrides(name).unwrap_or_else(||
+        ::enum_display::__private::ToString::to_string(self)) }
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(EnumDisplay)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
@@ -0,0 +1,5 @@
+#[derive(EnumDisplay)]
+enum Point {
+    TwoD(i32, i32),
+    ThreeD(i32, i32, i32),
+}
@@ -32,89 +32,4805 @@
 //! }
 //!
 //! assert_eq!(Message::HelloGreeting { name: "Alice".to_string() }.to_string(), "hello-greeting");
+//! ```
+//!
+//! Every variant's output can be wrapped with `prefix` and `suffix`, after the case transform.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Lower", prefix = "[", suffix = "]")]
+//! enum Tag {
+//!     Red,
+//!     Green,
+//! }
+//!
+//! assert_eq!(Tag::Red.to_string(), "[red]");
+//! ```
+//!
+//! `strip_prefix` and `strip_suffix` remove a naming convention from variant names before the case
+//! transform. Variants that don't follow it keep their whole name.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(strip_prefix = "Event", case = "Lower")]
+//! enum Event {
+//!     EventCreated,
+//!     EventDeleted,
+//!     Shutdown,
+//! }
+//!
+//! assert_eq!(Event::EventCreated.to_string(), "created");
+//! assert_eq!(Event::Shutdown.to_string(), "shutdown");
+//! ```
+//!
+//! `#[enum_display(qualified)]` writes names as `Enum::Variant`, which tells apart enums that
+//! share variant names in logs. `{variant}` is qualified as well, the enum's own name isn't
+//! affected by the case transform.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(qualified)]
+//! enum Signal {
+//!     Red,
+//!     #[display("{variant} for {0}s")]
+//!     Green(u32),
+//! }
+//!
+//! assert_eq!(Signal::Red.to_string(), "Signal::Red");
+//! assert_eq!(Signal::Green(30).to_string(), "Signal::Green for 30s");
+//! ```
+//!
+//! The case can also be written without quotes, as `case = Kebab` or `case = Case::Kebab`.
+//!
+//! Besides the cases from [`convert_case`](https://docs.rs/convert_case), `FirstUpper` and
+//! `FirstLower` only change the first character and leave the rest of the name untouched.
+//!
+//! Enums without a `case` of their own use the case named by the `ENUM_DISPLAY_DEFAULT_CASE`
+//! environment variable when it's set during the build, e.g. `ENUM_DISPLAY_DEFAULT_CASE=Kebab`.
+//! It only applies to the packages Cargo is building directly, the workspace members or the ones
+//! picked with `-p`, and never to dependencies, whose output stays whatever their authors wrote.
+//! Cargo doesn't know the derive reads it, so changing it doesn't rebuild crates that were
+//! already compiled, and the output then depends on which crates happened to be rebuilt. Set it
+//! in `.cargo/config.toml` under `[env]` and run `cargo clean` after changing it.
+//!
+//! Names are split into words wherever a lower case letter meets an upper case one, before the
+//! last capital of an acronym, and around digits, so `HTTPSConnection` becomes `https_connection`
+//! and `Http2Server` becomes `http_2_server` in snake case. `#[enum_display(boundaries = "...")]`
+//! picks the boundaries instead, given by example like `convert_case`'s `Boundary::list_from`:
+//! `aA` for lower to upper, `AAa` for acronyms, `a1`, `1a`, `A1` and `1A` for digits, separated
+//! by `:`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Snake", boundaries = "aA:1A")]
+//! enum Service {
+//!     Http2Server,
+//!     HTTPSConnection,
+//! }
+//!
+//! assert_eq!(Service::Http2Server.to_string(), "http2_server");
+//! assert_eq!(Service::HTTPSConnection.to_string(), "httpsconnection");
+//! ```
+//!
+//! A single variant can override the enum's case with `#[display(case = "...")]`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Kebab")]
+//! enum Header {
+//!     ContentType,
+//!     #[display(case = "ScreamingSnake")]
+//!     MaxAge,
+//! }
+//!
+//! assert_eq!(Header::ContentType.to_string(), "content-type");
+//! assert_eq!(Header::MaxAge.to_string(), "MAX_AGE");
+//! ```
+//!
+//! `#[display(uppercase)]` and `#[display(lowercase)]` force a variant's whole output into one
+//! case, after the case transform and after its format string has been filled in.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Kebab")]
+//! enum Level {
+//!     Info,
+//!     #[display(uppercase, "{variant}: {0}")]
+//!     Alert(&'static str),
+//! }
+//!
+//! assert_eq!(Level::Info.to_string(), "info");
+//! assert_eq!(Level::Alert("disk full").to_string(), "ALERT: DISK FULL");
+//! ```
+//!
+//! A case transform can make two variants display the same, which breaks parsing the output
+//! back. `#[enum_display(unique)]` makes that a compile error, for every variant whose output is
+//! known at compile time. Variants that interpolate fields aren't compared.
+//!
+//! ```compile_fail
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(unique, case = "Lower")]
+//! enum Unit {
+//!     MB,
+//!     Mb,
+//! }
+//! ```
+//!
+//! Unknown arguments are always an error. `#[enum_display(strict)]` also rejects what's otherwise
+//! accepted without effect: an argument given twice, where the last one wins, and `#[display]`
+//! on a field, which the derive never reads.
+//!
+//! ```compile_fail
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(strict, case = "Snake", case = "Kebab")]
+//! enum Color {
+//!     LightRed,
+//! }
+//! ```
+//!
+//! # Example With Format Strings
+//!
+//! A variant can be given its own format string with `#[display("...")]`. The variant's name is
+//! available as `{variant}`, the enum's name as `{enum}`, named fields by their name and tuple
+//! fields by their position. Named fields can be referred to by position too, in the order they
+//! are declared. `{enum}` is the enum's name as written, the case transform only applies to
+//! `{variant}`.
+//! Formatted variants are written straight into the [`std::fmt::Formatter`] without allocating.
+//!
+//! Format strings follow the same rules as [`std::format!`], so a literal brace is written by
+//! doubling it: `{{` and `}}`. Escaped braces are never treated as placeholders, so
+//! `#[display("{{{0}}}")]` writes field `0` surrounded by real braces.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Shape {
+//!     #[display("{variant} with radius {radius}")]
+//!     Circle { radius: u32 },
+//!     #[display("{variant} {0}x{1}")]
+//!     Rectangle(u32, u32),
+//!     Point,
+//! }
+//!
+//! assert_eq!(Shape::Circle { radius: 2 }.to_string(), "Circle with radius 2");
+//! assert_eq!(Shape::Rectangle(3, 4).to_string(), "Rectangle 3x4");
+//! assert_eq!(Shape::Point.to_string(), "Point");
+//!
+//! #[derive(EnumDisplay)]
+//! enum Set {
+//!     #[display("{{{0}}}")]
+//!     Single(u32),
+//! }
+//!
+//! assert_eq!(Set::Single(1).to_string(), "{1}");
+//! ```
+//!
+//! A fixed output that's a name itself can be written without quotes, `#[display(Crimson)]` is
+//! the same as `#[display("Crimson")]` and `#[display(Color::Red)]` writes `Color::Red`. Only
+//! names starting with an upper case letter or paths with more than one segment are taken this
+//! way, a lower case name is still checked against the arguments `display` accepts.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Color {
+//!     #[display(Crimson)]
+//!     Red,
+//!     #[display(Color::Green)]
+//!     Green,
+//! }
+//!
+//! assert_eq!(Color::Red.to_string(), "Crimson");
+//! assert_eq!(Color::Green.to_string(), "Color::Green");
+//! ```
+//!
+//! Multiline templates can be indented along with the code around them with `dedent`, on a single
+//! `#[display(dedent, "...")]` or on `#[enum_display(dedent)]` for every variant. Like `indoc!`, it
+//! drops a leading newline and removes the indentation the remaining lines share.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Report {
+//!     #[display(dedent, "
+//!         {variant}:
+//!           {0} errors")]
+//!     Summary(u32),
+//! }
+//!
+//! assert_eq!(Report::Summary(2).to_string(), "Summary:\n  2 errors");
+//! ```
+//!
+//! # Static Variant Names
+//!
+//! The derive also adds a `const fn variant_name(&self) -> &'static str`, returning the string
+//! the variant displays as without allocating. Variants whose format string interpolates fields
+//! (or that are `transparent`) return their transformed name instead, since their output isn't
+//! known until runtime. Like every method the derive adds, it's as visible as the enum itself, so
+//! a `pub(crate)` enum gets `pub(crate)` methods.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Kebab")]
+//! enum Event {
+//!     KeyDown,
+//!     #[display("{variant} at {0},{1}")]
+//!     MouseMove(i32, i32),
+//! }
+//!
+//! const KEY_DOWN: &str = Event::KeyDown.variant_name();
+//! assert_eq!(KEY_DOWN, "key-down");
+//! assert_eq!(Event::MouseMove(1, 2).variant_name(), "mouse-move");
+//! ```
+//!
+//! With the `alloc` feature, which `std` enables, `display_cow()` returns a
+//! [`Cow<'static, str>`](alloc::borrow::Cow) that borrows the output of variants whose
+//! output is known at compile time and only allocates for the rest.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! use enum_display::EnumDisplay;
+//! use std::borrow::Cow;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Event {
+//!     Click,
+//!     #[display("{variant} {0}")]
+//!     Key(char),
+//! }
+//!
+//! assert!(matches!(Event::Click.display_cow(), Cow::Borrowed("Click")));
+//! assert_eq!(Event::Key('a').display_cow(), "Key a");
+//! # }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! `display_names()` lists the name of every variant in declaration order, which is handy for
+//! menus or iterating over every option. `variant_index()` is the variant's position in that
+//! order, independent of any explicit discriminant.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Kebab")]
+//! enum Theme {
+//!     LightMode,
+//!     DarkMode,
+//! }
+//!
+//! assert_eq!(Theme::display_names(), ["light-mode", "dark-mode"]);
+//! let theme = Theme::DarkMode;
+//! assert_eq!(Theme::display_names()[theme.variant_index()], theme.variant_name());
+//! ```
+//!
+//! Enums made up only of unit variants also get `as_cstr()`, the same name as a nul-terminated
+//! `&'static CStr` for passing to C APIs without allocating a `CString`. A name with a nul byte
+//! in it can't be a C string, so the method is left out for these.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Lower")]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//! }
+//!
+//! assert_eq!(Level::Warn.as_cstr().to_str(), Ok("warn"));
+//! ```
+//!
+//! They get `iter()` too, which yields every variant in declaration order. Enums with fields
+//! don't, since their variants can't be built without values for the fields.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay)]
+//! enum Level {
+//!     Info,
+//!     Warn,
+//! }
+//!
+//! assert_eq!(Level::iter().collect::<Vec<_>>(), [Level::Info, Level::Warn]);
+//! ```
+//!
+//! Enums with an integer `#[repr(...)]` get `from_repr()`, the inverse of casting a variant to its
+//! discriminant. It takes the `#[repr]` type and returns the unit variant with that discriminant,
+//! or `None` when there isn't one. Variants with fields can't be built from a number alone, so
+//! they're never returned.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay)]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     Nop = 0x90,
+//!     Ret = 0xc3,
+//!     Int3 = 0xcc,
+//! }
+//!
+//! assert_eq!(Opcode::from_repr(0xc3), Some(Opcode::Ret));
+//! assert_eq!(Opcode::from_repr(0x00), None);
+//! ```
+//!
+//! `display_with()`, also behind `alloc`, lets a translation layer replace a variant's output at
+//! runtime. The closure is called with the variant's name as declared, and returning `None`
+//! keeps the normal output.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Lower")]
+//! enum Greeting {
+//!     Hello,
+//!     #[display("bye {0}")]
+//!     Goodbye(&'static str),
+//! }
+//!
+//! let french = |name: &str| match name {
+//!     "Hello" => Some("bonjour".to_string()),
+//!     _ => None,
+//! };
+//! assert_eq!(Greeting::Hello.display_with(&french), "bonjour");
+//! assert_eq!(Greeting::Goodbye("Ann").display_with(&french), "bye Ann");
+//! # }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! `#[display(join = "...")]` writes every field of a tuple variant separated by the given
+//! string, however many fields it has.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Row {
+//!     #[display(join = " | ")]
+//!     Cells(&'static str, u32, bool),
+//! }
+//!
+//! assert_eq!(Row::Cells("a", 1, true).to_string(), "a | 1 | true");
+//! ```
+//!
+//! The last field of a tuple variant is also available as `{last}`, so long variants don't need
+//! their fields counted.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Row {
+//!     #[display("{variant} total {last}")]
+//!     Sales(&'static str, u32, u32, u32),
+//! }
+//!
+//! assert_eq!(Row::Sales("north", 1, 2, 3).to_string(), "Sales total 3");
+//! ```
+//!
+//! `{arity}` is the number of fields the variant has, zero for unit variants. It's known at
+//! compile time, so a unit variant that uses it still has a fixed output.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Op {
+//!     #[display("{variant}/{arity}")]
+//!     Add(i32, i32),
+//!     #[display("{variant}/{arity}")]
+//!     Nop,
+//! }
+//!
+//! assert_eq!(Op::Add(1, 2).to_string(), "Add/2");
+//! assert_eq!(Op::Nop.variant_name(), "Nop/0");
+//! ```
+//!
+//! Tuple fields can also be given names with `fields(...)`, which must list one name per field.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Location {
+//!     #[display(fields("lat", "lon"), "{lat},{lon}")]
+//!     Coordinates(f64, f64),
+//! }
+//!
+//! assert_eq!(Location::Coordinates(51.5, -0.1).to_string(), "51.5,-0.1");
+//! ```
+//!
+//! A default format string for every variant can be set on the enum with
+//! `#[enum_display(format = "...")]`, variants with their own `#[display("...")]` use that instead.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(format = "<{variant}>")]
+//! enum Tag {
+//!     Bold,
+//!     #[display("<{variant} size={0}>")]
+//!     Font(u32),
+//! }
+//!
+//! assert_eq!(Tag::Bold.to_string(), "<Bold>");
+//! assert_eq!(Tag::Font(12).to_string(), "<Font size=12>");
+//! ```
+//!
+//! Values computed from the fields can be bound with `bind(name = "expression")`. The fields are
+//! references, and bindings are evaluated after any tuple field names.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Total {
+//!     #[display(bind(sum = "x + y"), "sum is {sum}")]
+//!     Sum { x: i32, y: i32 },
+//! }
+//!
+//! assert_eq!(Total::Sum { x: 2, y: 3 }.to_string(), "sum is 5");
+//! ```
+//!
+//! A binding can reuse a field's name, and then `{name}` is the binding. The field can still be
+//! written by its position, since `{0}` always means the first declared field. Likewise
+//! `{variant}` and `{enum}` are always the names, and a field called `variant` or `enum` is
+//! still written by its position.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Address {
+//!     #[display(bind(city = "city.to_uppercase()"), "{0}, {city} (was {1})")]
+//!     Line { street: &'static str, city: &'static str },
+//! }
+//!
+//! let line = Address::Line { street: "Main St", city: "Oslo" };
+//! assert_eq!(line.to_string(), "Main St, OSLO (was Oslo)");
+//! ```
+//!
+//! Bindings are evaluated inside a method taking `&self`, so a method of the whole value can be
+//! called with `bind(name = "self.method()")`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Shape {
+//!     #[display(bind(area = "self.area()"), "{variant} of area {area}")]
+//!     Square(u32),
+//! }
+//!
+//! impl Shape {
+//!     fn area(&self) -> u32 {
+//!         match self {
+//!             Shape::Square(side) => side * side,
+//!         }
+//!     }
+//! }
+//!
+//! assert_eq!(Shape::Square(3).to_string(), "Square of area 9");
+//! ```
+//!
+//! Tuple variants can convert their fields with `cast = "u64"`, which binds every field as
+//! `*field as u64` before anything else. Small integers can then be used in bindings without
+//! overflowing their own type.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Size {
+//!     #[display(cast = "u32", fields(kib), bind(bytes = "kib * 1024"), "{bytes} bytes")]
+//!     Kib(u8),
+//! }
+//!
+//! assert_eq!(Size::Kib(200).to_string(), "204800 bytes");
+//! ```
 //!
+//! Integer fields listed in `group_digits(...)`, by position or by name, are written with their
+//! digits grouped in threes by commas, like `1,000,000`. Every placeholder for the field is
+//! grouped, with its width and alignment applied to the grouped number. Bindings still see the
+//! field as it is.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Stat {
+//!     #[display(group_digits(0), "{0} downloads")]
+//!     Downloads(u64),
+//!     #[display(group_digits(delta), "{delta:>8}")]
+//!     Change { delta: i64 },
+//! }
+//!
+//! assert_eq!(Stat::Downloads(1_250_000).to_string(), "1,250,000 downloads");
+//! assert_eq!(Stat::Change { delta: -4200 }.to_string(), "  -4,200");
+//! ```
+//!
+//! `trim` drops the whitespace around the formatted output, so padded fields or an empty field
+//! at either end don't leave stray spaces. It trims the whole output, including the enum's prefix
+//! and suffix, and needs a format string.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Name {
+//!     #[display(trim, "{first} {last}")]
+//!     Person { first: &'static str, last: &'static str },
+//! }
+//!
+//! assert_eq!(Name::Person { first: "Cher", last: "" }.to_string(), "Cher");
+//! ```
+//!
+//! `truncate = 20` keeps the first 20 characters of the output and ends it with `…` when anything
+//! was cut off. Characters are counted rather than bytes, so the cut never splits one, and the
+//! prefix and suffix count toward the length. It works on plain names too, and on everything but
+//! `with`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Label {
+//!     #[display(truncate = 8, "{0}")]
+//!     Title(&'static str),
+//!     #[display(truncate = 4)]
+//!     Untitled,
+//! }
+//!
+//! assert_eq!(Label::Title("Crème brûlée").to_string(), "Crème br…");
+//! assert_eq!(Label::Title("Short").to_string(), "Short");
+//! assert_eq!(Label::Untitled.to_string(), "Unti…");
+//! ```
+//!
+//! Constants and statics in scope can be written into the output too, once they're listed with
+//! `extern(...)`. Every other name has to be something the derive binds, so typos are still
+//! caught. Associated constants need a path, so they're bound instead, e.g.
+//! `bind(max = "Self::MAX")`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! const VERSION: u32 = 2;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Protocol {
+//!     #[display(extern(VERSION), "{variant} v{VERSION}")]
+//!     Http,
+//! }
+//!
+//! assert_eq!(Protocol::Http.to_string(), "Http v2");
+//! ```
+//!
+//! `{variant}` uses the same case as the plain names unless the enum sets a separate
+//! `variant_case`. A case set on the variant itself wins over both.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Snake", variant_case = "Title")]
+//! enum Job {
+//!     NightlyBuild,
+//!     #[display("{variant} #{0}")]
+//!     ReleaseBuild(u32),
+//! }
+//!
+//! assert_eq!(Job::NightlyBuild.to_string(), "nightly_build");
+//! assert_eq!(Job::ReleaseBuild(3).to_string(), "Release Build #3");
+//! ```
+//!
+//! `#[enum_display(rename_all_fields = "...")]` also lets named fields be referred to in another
+//! case, like serde's `rename_all`, so a `firstName` field can be written as `{first_name}`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[allow(non_snake_case)]
+//! #[derive(EnumDisplay)]
+//! #[enum_display(rename_all_fields = "Snake")]
+//! enum Contact {
+//!     #[display("{first_name} {lastName}")]
+//!     Person { firstName: &'static str, lastName: &'static str },
+//! }
+//!
+//! let person = Contact::Person { firstName: "Ada", lastName: "Lovelace" };
+//! assert_eq!(person.to_string(), "Ada Lovelace");
+//! ```
+//!
+//! `{variant}` always refers to the variant's name, and `{enum}` to the enum's, so a field that
+//! is itself called `variant` has to be written by its position or bound under another name,
+//! e.g. `bind(kind = "variant")`. Using `{variant}` in a variant with such a field that the
+//! format doesn't otherwise refer to is an error, since the field was most likely meant.
+//!
+//! `{discriminant}` is the variant's discriminant, counted up from the last explicit one like the
+//! compiler does. It's an `isize` unless the enum has an integer `#[repr(...)]`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(format = "{discriminant} {variant}")]
+//! #[repr(u16)]
+//! enum Status {
+//!     Ok = 200,
+//!     Created,
+//!     NotFound = 404,
+//! }
+//!
+//! assert_eq!(Status::Ok.to_string(), "200 Ok");
+//! assert_eq!(Status::Created.to_string(), "201 Created");
+//! assert_eq!(Status::NotFound.to_string(), "404 NotFound");
+//! ```
+//!
+//! Crates where another derive already uses `#[display]` can write the same arguments as
+//! `#[enum_display(...)]` on the variant instead, with `as = "..."` for the format string.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Planet {
+//!     #[enum_display(as = "the {variant}")]
+//!     Earth,
+//!     #[enum_display(case = "Upper")]
+//!     Mars,
+//! }
+//!
+//! assert_eq!(Planet::Earth.to_string(), "the Earth");
+//! assert_eq!(Planet::Mars.to_string(), "MARS");
+//! ```
+//!
+//! # Serde Renames
+//!
+//! With `#[enum_display(use_serde_rename)]`, a variant's `#[serde(rename = "...")]` is used as
+//! its display name so `Display` matches the serialized form. A variant's own `#[display]` still
+//! wins. Serde isn't a dependency of this crate, the attribute is only read.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//! use serde::Serialize;
+//!
+//! #[derive(EnumDisplay, Serialize)]
+//! #[enum_display(use_serde_rename)]
+//! enum Status {
+//!     #[serde(rename = "in_progress")]
+//!     InProgress,
+//!     Done,
+//! }
+//!
+//! assert_eq!(Status::InProgress.to_string(), "in_progress");
+//! assert_eq!(Status::Done.to_string(), "Done");
+//! ```
+//!
+//! # Groups
+//!
+//! Variants that should all display the same way can share a string declared once on the enum
+//! with `group(name = "...")`, and join the group with `#[display(group = "name")]`. The string is
+//! used as is, like a plain name, so it can't refer to fields.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(group(failed = "request failed"))]
+//! enum Response {
+//!     Success,
+//!     #[display(group = "failed")]
+//!     Timeout,
+//!     #[display(group = "failed")]
+//!     Refused(u16),
+//! }
+//!
+//! assert_eq!(Response::Success.to_string(), "Success");
+//! assert_eq!(Response::Timeout.to_string(), "request failed");
+//! assert_eq!(Response::Refused(503).to_string(), "request failed");
+//! ```
+//!
+//! # Quoted Output
+//!
+//! `#[display(quote)]`, or `#[enum_display(quote)]` for every variant, wraps the output in double
+//! quotes and escapes any `"` or `\` inside it, including the ones written by interpolated fields.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Token {
+//!     #[display(quote)]
+//!     Keyword,
+//!     #[display(quote, "{0}")]
+//!     Text(&'static str),
+//! }
+//!
+//! assert_eq!(Token::Keyword.to_string(), r#""Keyword""#);
+//! assert_eq!(Token::Text(r#"say "hi""#).to_string(), r#""say \"hi\"""#);
+//! ```
+//!
+//! `#[enum_display(json)]` quotes every variant as a JSON string instead, the way `serde_json`
+//! would write it, so newlines and other control characters are escaped as well. This is handy
+//! for logging an enum as JSON without depending on serde.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(json, case = "Lower")]
+//! enum Color {
+//!     Red,
+//!     #[display("dark\ngreen")]
+//!     DarkGreen,
+//! }
+//!
+//! assert_eq!(Color::Red.to_string(), r#""red""#);
+//! assert_eq!(Color::DarkGreen.to_string(), r#""dark\ngreen""#);
+//! ```
+//!
+//! # ASCII Only
+//!
+//! Enums that have to stay ASCII, like the commands of a text protocol, can be checked with
+//! `#[enum_display(ascii_only)]`. Every variant name, format string, prefix and suffix is checked
+//! at compile time, and any non-ASCII character is an error. Fields are formatted at runtime, so
+//! what they write can't be checked.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(ascii_only, case = "Upper")]
+//! enum Command {
+//!     Quit,
+//!     #[display("USER {0}")]
+//!     User(&'static str),
+//! }
+//!
+//! assert_eq!(Command::Quit.to_string(), "QUIT");
+//! assert_eq!(Command::User("ferris").to_string(), "USER ferris");
+//! ```
+//!
+//! # Transparent Variants
+//!
+//! A variant with exactly one field can forward to that field's `Display` with
+//! `#[display(transparent)]`, like `thiserror`'s `#[error(transparent)]`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Fruit {
+//!     Apple,
+//!     #[display(transparent)]
+//!     Other(String),
+//! }
+//!
+//! assert_eq!(Fruit::Other("Kiwi".to_string()).to_string(), "Kiwi");
+//! ```
+//!
+//! The formatter is handed to the field as is, so flags like `{:#}` or a width apply to the
+//! field's own output.
+//!
+//! # Formatting Functions
+//!
+//! Rendering that doesn't fit a format string can live in a function with
+//! `#[display(with = "path::to::function")]`. The function is called with the formatter and a
+//! reference to each field in declaration order, and returns `fmt::Result`, e.g.
+//! `fn fmt_point(f: &mut Formatter, x: &i32, y: &i32) -> fmt::Result`. Unit variants only get the
+//! formatter. The enum's prefix and suffix are still written around its output.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//! use std::fmt;
+//!
+//! fn fmt_point(f: &mut fmt::Formatter, x: &i32, y: &i32) -> fmt::Result {
+//!     if *x == 0 && *y == 0 {
+//!         f.write_str("origin")
+//!     } else {
+//!         write!(f, "({}, {})", x, y)
+//!     }
+//! }
+//!
+//! #[derive(EnumDisplay)]
+//! enum Shape {
+//!     #[display(with = "fmt_point")]
+//!     Point { x: i32, y: i32 },
+//! }
+//!
+//! assert_eq!(Shape::Point { x: 0, y: 0 }.to_string(), "origin");
+//! assert_eq!(Shape::Point { x: 1, y: 2 }.to_string(), "(1, 2)");
+//! ```
+//!
+//! The derive's output is also available as an inherent `write_display(&self, f)`, which works
+//! without `Display` in scope and composes into hand written `Display` impls of wrapper types.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//! use std::fmt;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Status {
+//!     Ready,
+//! }
+//!
+//! struct Labelled(Status);
+//!
+//! impl fmt::Display for Labelled {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         f.write_str("status: ")?;
+//!         self.0.write_display(f)
+//!     }
+//! }
+//!
+//! assert_eq!(Labelled(Status::Ready).to_string(), "status: Ready");
+//! ```
+//!
+//! `display_into(&mut buf)` writes the same output into anything that implements
+//! [`core::fmt::Write`], like a fixed size buffer on a target without an allocator. It's
+//! generated next to `fmt` rather than forwarding to it: plain names are handed to the writer as
+//! they are, and format strings are written into it with `write!`. Only `transparent` and `with`
+//! variants, which need a [`core::fmt::Formatter`] of their own, go through `Display`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Status {
+//!     #[display("{variant} in {0}s")]
+//!     Retrying(u32),
+//! }
+//!
+//! let mut buf = String::new();
+//! Status::Retrying(5).display_into(&mut buf).unwrap();
+//! assert_eq!(buf, "Retrying in 5s");
+//! ```
+//!
+//! # Formatter Flags
+//!
+//! Plain names are written with [`std::fmt::Formatter::write_str`] by default, so the width,
+//! alignment, fill and precision of the formatter are ignored. With `#[enum_display(pad)]`
+//! variants without a format string are written with [`std::fmt::Formatter::pad`] instead and
+//! respect them, just like a `&str` would, so `{:.3}` keeps the first three characters.
+//! Formatted variants are written with `write!`, so their layout is controlled entirely by their
+//! own format string.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(pad)]
+//! enum Color {
+//!     Red,
+//!     #[display("{variant}!")]
+//!     Green,
+//! }
+//!
+//! #[derive(EnumDisplay)]
+//! enum Size {
+//!     Small,
+//! }
+//!
+//! assert_eq!(format!("{:>8}", Color::Red), "     Red");
+//! assert_eq!(format!("{:-^7}", Color::Red), "--Red--");
+//! assert_eq!(format!("{:>8}", Color::Green), "Green!");
+//! assert_eq!(format!("{:.2}", Color::Red), "Re");
+//! assert_eq!(format!("{:>8}", Size::Small), "Small");
+//! assert_eq!(format!("{:.2}", Size::Small), "Small");
+//! ```
+//!
+//! # Debug
+//!
+//! `#[enum_display(debug)]` also implements [`std::fmt::Debug`] with the same output as `Display`.
+//! It replaces `#[derive(Debug)]`, deriving both is a conflicting implementation error.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(debug, case = "Kebab")]
+//! enum Key {
+//!     NotFound,
+//!     #[display("{variant}-{0}")]
+//!     Code(u32),
+//! }
+//!
+//! assert_eq!(format!("{:?}", Key::NotFound), "not-found");
+//! assert_eq!(format!("{:?}", Key::Code(4)), "code-4");
+//! ```
+//!
+//! The other way around, `#[display(debug_fields)]` writes a variant the way derived `Debug` would,
+//! using each field's `Debug`. On the enum, `#[enum_display(debug_fields)]` does the same for every
+//! variant with fields that doesn't have a format of its own.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(debug_fields)]
+//! enum Command {
+//!     Quit,
+//!     Move(i32, i32),
+//!     Say { text: &'static str },
+//! }
+//!
+//! assert_eq!(Command::Quit.to_string(), "Quit");
+//! assert_eq!(Command::Move(1, -1).to_string(), "Move(1, -1)");
+//! assert_eq!(Command::Say { text: "hi" }.to_string(), r#"Say { text: "hi" }"#);
+//! ```
+//!
+//! `#[display(all_fields)]` lays out a variant with named fields the same way, but with each
+//! field's `Display`. The fields are separated by `, ` unless another separator is given, like
+//! `all_fields = "; "`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Shape {
+//!     #[display(all_fields)]
+//!     Rect { w: u32, h: u32 },
+//!     #[display(all_fields = "; ")]
+//!     Circle { x: i32, y: i32, r: u32 },
+//! }
+//!
+//! assert_eq!(Shape::Rect { w: 2, h: 3 }.to_string(), "Rect { w: 2, h: 3 }");
+//! assert_eq!(
+//!     Shape::Circle { x: 0, y: -1, r: 5 }.to_string(),
+//!     "Circle { x: 0; y: -1; r: 5 }"
+//! );
+//! ```
+//!
+//! For fields that only implement `Debug`, `#[enum_display(field_repr = "debug")]` writes every
+//! field placeholder without a spec, like `{0}` or `{name}`, as if it were `{0:?}`. Placeholders
+//! with a spec of their own, bindings and constants are left alone, and a variant can go back to
+//! `Display` with `#[display(field_repr = "display")]`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(Debug)]
+//! struct Point {
+//!     x: i32,
+//! }
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(field_repr = "debug")]
+//! enum Event {
+//!     #[display("{variant} at {0}")]
+//!     Click(Point),
+//!     #[display(field_repr = "display", "{variant} {0}")]
+//!     Key(char),
+//! }
+//!
+//! assert_eq!(Event::Click(Point { x: 3 }).to_string(), "Click at Point { x: 3 }");
+//! assert_eq!(Event::Key('q').to_string(), "Key q");
+//! ```
+//!
+//! `#[enum_display(debug_alternate)]` keeps both forms in the one impl: `{}` writes the usual
+//! output, and the alternate `{:#}` writes the variant's name and fields like `debug_fields`. The
+//! enum's prefix, suffix and quoting apply to both, and so does `pad` for variants without fields.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(debug_alternate)]
+//! enum Event {
+//!     #[display("{variant} by {user}")]
+//!     Login { user: &'static str, attempts: u8 },
+//! }
+//!
+//! let event = Event::Login { user: "ann", attempts: 2 };
+//! assert_eq!(format!("{}", event), "Login by ann");
+//! assert_eq!(format!("{:#}", event), r#"Login { user: "ann", attempts: 2 }"#);
+//! ```
+//!
+//! # Inlining
+//!
+//! The generated `fmt` is marked `#[inline]` when no variant has a format string. Formatted
+//! variants can expand to a lot of code, so enums with them are only inlined when they opt in
+//! with `#[enum_display(inline)]`.
+//!
+//! # Fast Numeric Fields
+//!
+//! With the `fast_numeric` feature, `#[enum_display(fast_numeric)]` writes integer fields with
+//! [`itoa`](https://docs.rs/itoa) instead of going through `write!`. It applies to variants whose
+//! format string only interpolates fields of a primitive integer type, `{variant}` and `{enum}`,
+//! all without a spec. Anything else, like `{0:>4}` or a float field, is formatted as usual, so
+//! the output is the same either way.
+//!
+//! ```rust
+//! # #[cfg(feature = "fast_numeric")]
+//! # fn main() {
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(fast_numeric)]
+//! enum Metric {
+//!     #[display("{variant}={0}")]
+//!     Count(u64),
+//!     #[display("{variant}={0}")]
+//!     Ratio(f64),
+//! }
+//!
+//! assert_eq!(Metric::Count(42).to_string(), "Count=42");
+//! assert_eq!(Metric::Ratio(1.0).to_string(), "Ratio=1");
+//! # }
+//! # #[cfg(not(feature = "fast_numeric"))]
+//! # fn main() {}
+//! ```
+//!
+//! # Coverage
+//!
+//! `#[enum_display(no_coverage)]` marks the generated functions `#[coverage(off)]` when the
+//! `coverage_nightly` cfg is set, which `cargo llvm-cov` does on nightly, so enums that are never
+//! formatted in tests don't show up as uncovered code. The crate using it needs
+//! `#![cfg_attr(coverage_nightly, feature(coverage_attribute))]` and should declare the cfg:
+//!
+//! ```toml
+//! [lints.rust]
+//! unexpected_cfgs = { level = "warn", check-cfg = ["cfg(coverage_nightly)"] }
+//! ```
+//!
+//! # Structs
+//!
+//! `EnumDisplay` can also be derived on structs, which display like an enum with a single variant
+//! named after the struct. The same attributes apply, with `#[display("...")]` written on the
+//! struct itself.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! #[enum_display(case = "Lower")]
+//! struct Meters;
+//!
+//! #[derive(EnumDisplay)]
+//! #[display("{0}m")]
+//! struct Distance(f64);
+//!
+//! assert_eq!(Meters.to_string(), "meters");
+//! assert_eq!(Distance(1.5).to_string(), "1.5m");
+//! ```
+//!
+//! # Generics
+//!
+//! Generic enums don't need to declare display bounds themselves. The derive bounds the type of
+//! every interpolated field that uses a type parameter with the trait its placeholder needs, so
+//! `{0}` adds `T: Display` and `{0:?}` adds `T: Debug`.
+//!
+//! ```rust
+//! use enum_display::EnumDisplay;
+//!
+//! #[derive(EnumDisplay)]
+//! enum Reading<T> {
+//!     #[display("{0} degrees")]
+//!     Temperature(T),
+//!     Missing,
+//! }
+//!
+//! assert_eq!(Reading::Temperature(21.5).to_string(), "21.5 degrees");
+//! assert_eq!(Reading::<f64>::Missing.to_string(), "Missing");
+//! ```
+//!
+//! Bounds the enum does declare, inline or in a `where` clause, are kept on every generated impl.
+//! The inferred bounds are added to the end of the same `where` clause, so a bound that's both
+//! declared and inferred is simply required twice. A field that's a reference is bounded through
+//! the type it points to, `&'a T` adds `T: Display`, and a field shadowed by a `bind(...)` isn't
+//! bounded at all.
+//!
+//! # Parsing With `EnumFromStr`
+//!
+//! Enums with only unit variants can also derive [`std::str::FromStr`], which accepts exactly the
+//! strings the `Display` impl produces. Deriving it on an enum with fields is a compile error,
+//! since those variants can't be rebuilt from their name alone.
+//!
+//! ```rust
+//! use enum_display::{EnumDisplay, EnumFromStr};
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+//! #[enum_display(case = "Kebab")]
+//! enum Level {
+//!     LowPriority,
+//!     #[display("urgent!")]
+//!     HighPriority,
+//! }
+//!
+//! assert_eq!("low-priority".parse(), Ok(Level::LowPriority));
+//! assert_eq!("urgent!".parse(), Ok(Level::HighPriority));
+//! assert!("LowPriority".parse::<Level>().is_err());
+//! ```
+//!
+//! Marking one variant `#[display(default)]` also generates `from_str_or_default`, which returns
+//! that variant for strings that don't match any other.
+//!
+//! ```rust
+//! use enum_display::{EnumDisplay, EnumFromStr};
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+//! #[enum_display(case = "Lower")]
+//! enum Mode {
+//!     Fast,
+//!     #[display(default)]
+//!     Normal,
+//! }
+//!
+//! assert_eq!(Mode::from_str_or_default("fast"), Mode::Fast);
+//! assert_eq!(Mode::from_str_or_default("turbo"), Mode::Normal);
+//! ```
+//!
+//! With `#[enum_display(parse_case_insensitive)]` the input is matched in any case, which suits
+//! command line flags and environment variables. Both the input and the variant strings are
+//! lowercased before they're compared, so variants whose strings only differ in case would be
+//! ambiguous and are a compile error. `Display` is unaffected.
+//!
+//! ```rust
+//! use enum_display::{EnumDisplay, EnumFromStr};
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+//! #[enum_display(case = "Kebab", parse_case_insensitive)]
+//! enum Format {
+//!     Json,
+//!     PlainText,
+//! }
+//!
+//! assert_eq!("JSON".parse(), Ok(Format::Json));
+//! assert_eq!("Plain-Text".parse(), Ok(Format::PlainText));
+//! assert_eq!(Format::PlainText.to_string(), "plain-text");
+//! ```
+//!
+//! `EnumTryFrom` generates the same mapping as a `TryFrom<&str>` impl instead.
+//!
+//! ```rust
+//! use enum_display::{EnumDisplay, EnumTryFrom};
+//!
+//! #[derive(Debug, PartialEq, EnumDisplay, EnumTryFrom)]
+//! #[enum_display(case = "Lower")]
+//! enum Color {
+//!     Red,
+//!     Green,
+//! }
+//!
+//! assert_eq!(Color::try_from("red"), Ok(Color::Red));
+//! assert!(Color::try_from("Red").is_err());
+//! ```
+//!
+//! # Renamed Dependencies
+//!
+//! The generated code refers to items of this crate through `::enum_display`. When the crate is
+//! renamed in `Cargo.toml` or re-exported from a facade crate, point the derive at it with
+//! `#[enum_display(crate = "path::to::enum_display")]`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+// Lets the generated code refer to `::enum_display` from inside this crate's own tests
+extern crate self as enum_display;
+
+pub use enum_display_macro::*;
+
+// Items the generated code refers to, not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::borrow::Cow;
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::{String, ToString};
+
+    /// Escapes `"` and `\` in everything written through it, for `#[display(quote)]`.
+    pub struct EscapeQuotes<W>(pub W);
+
+    impl<W: core::fmt::Write> core::fmt::Write for EscapeQuotes<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let mut start = 0;
+            for (i, c) in s.char_indices() {
+                if c == '"' || c == '\\' {
+                    self.0.write_str(&s[start..i])?;
+                    self.0.write_str("\\")?;
+                    start = i;
+                }
+            }
+            self.0.write_str(&s[start..])
+        }
+    }
+
+    /// Escapes everything written through it like a JSON string, for `#[enum_display(json)]`.
+    pub struct EscapeJson<W>(pub W);
+
+    impl<W: core::fmt::Write> core::fmt::Write for EscapeJson<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let mut start = 0;
+            for (i, c) in s.char_indices() {
+                // Other control characters have no short escape and are written as `\u00XX`
+                let escaped = match c {
+                    '"' => Some("\\\""),
+                    '\\' => Some("\\\\"),
+                    '\n' => Some("\\n"),
+                    '\r' => Some("\\r"),
+                    '\t' => Some("\\t"),
+                    '\u{8}' => Some("\\b"),
+                    '\u{c}' => Some("\\f"),
+                    c if c < ' ' => None,
+                    _ => continue,
+                };
+                self.0.write_str(&s[start..i])?;
+                start = i + c.len_utf8();
+                match escaped {
+                    Some(escaped) => self.0.write_str(escaped)?,
+                    None => {
+                        const HEX: &[u8; 16] = b"0123456789abcdef";
+                        let byte = c as usize;
+                        self.0.write_str("\\u00")?;
+                        self.0.write_char(HEX[byte >> 4] as char)?;
+                        self.0.write_char(HEX[byte & 0xf] as char)?;
+                    }
+                }
+            }
+            self.0.write_str(&s[start..])
+        }
+    }
+
+    /// Uppercases everything written through it, for `#[display(uppercase)]`.
+    pub struct Uppercase<W>(pub W);
+
+    impl<W: core::fmt::Write> core::fmt::Write for Uppercase<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            s.chars()
+                .flat_map(char::to_uppercase)
+                .try_for_each(|c| self.0.write_char(c))
+        }
+    }
+
+    /// Lowercases everything written through it, for `#[display(lowercase)]`.
+    pub struct Lowercase<W>(pub W);
+
+    impl<W: core::fmt::Write> core::fmt::Write for Lowercase<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            s.chars()
+                .flat_map(char::to_lowercase)
+                .try_for_each(|c| self.0.write_char(c))
+        }
+    }
+
+    /// Writes at most `max` characters of everything written through it, then `…` if anything
+    /// was cut off, for `#[display(truncate = 20)]`.
+    pub struct Truncate<W> {
+        inner: W,
+        remaining: usize,
+        cut: bool,
+    }
+
+    impl<W: core::fmt::Write> Truncate<W> {
+        pub fn new(inner: W, max: usize) -> Self {
+            Self {
+                inner,
+                remaining: max,
+                cut: false,
+            }
+        }
+
+        pub fn finish(mut self) -> core::fmt::Result {
+            if self.cut {
+                self.inner.write_str("…")?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<W: core::fmt::Write> core::fmt::Write for Truncate<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            if self.cut {
+                return Ok(());
+            }
+            // Counting characters rather than bytes keeps the cut on a character boundary
+            match s.char_indices().nth(self.remaining) {
+                Some((end, _)) => {
+                    self.inner.write_str(&s[..end])?;
+                    self.remaining = 0;
+                    self.cut = true;
+                }
+                None => {
+                    self.inner.write_str(s)?;
+                    self.remaining -= s.chars().count();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // Inherent so `write!` works without `fmt::Write` in scope at the call site
+    macro_rules! inherent_write_fmt {
+        ($($writer:ident),*) => {
+            $(
+                impl<W: core::fmt::Write> $writer<W> {
+                    pub fn write_fmt(&mut self, args: core::fmt::Arguments) -> core::fmt::Result {
+                        core::fmt::Write::write_fmt(self, args)
+                    }
+                }
+            )*
+        };
+    }
+
+    inherent_write_fmt!(EscapeQuotes, EscapeJson, Uppercase, Lowercase, Truncate);
+
+    /// The C string for `as_cstr`, evaluated in a const so a bad name fails at compile time.
+    pub const fn cstr(bytes: &'static [u8]) -> &'static core::ffi::CStr {
+        match core::ffi::CStr::from_bytes_with_nul(bytes) {
+            Ok(cstr) => cstr,
+            Err(_) => panic!("variant names can't contain nul bytes"),
+        }
+    }
+
+    /// Whether `s` lowercased equals `lowercase`, for `#[enum_display(parse_case_insensitive)]`.
+    pub fn eq_lowercase(s: &str, lowercase: &str) -> bool {
+        s.chars().flat_map(char::to_lowercase).eq(lowercase.chars())
+    }
+
+    /// Writes `args` without its leading and trailing whitespace, for `#[display(trim)]`.
+    ///
+    /// The output is trimmed as it's written, so `args` is only formatted once. Whitespace after
+    /// the last non-whitespace character so far is held back until more text shows it isn't
+    /// trailing.
+    pub fn write_trimmed<W: core::fmt::Write>(
+        writer: W,
+        args: core::fmt::Arguments,
+    ) -> core::fmt::Result {
+        let mut trimmed = Trimmed {
+            inner: writer,
+            started: false,
+            pending: Pending::new(),
+        };
+        core::fmt::Write::write_fmt(&mut trimmed, args)
+    }
+
+    struct Trimmed<W> {
+        inner: W,
+        // Whether anything but whitespace has been written yet
+        started: bool,
+        pending: Pending,
+    }
+
+    impl<W: core::fmt::Write> core::fmt::Write for Trimmed<W> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let s = if self.started { s } else { s.trim_start() };
+            if s.is_empty() {
+                return Ok(());
+            }
+            self.started = true;
+            let text = s.trim_end();
+            if !text.is_empty() {
+                self.pending.flush(&mut self.inner)?;
+                self.inner.write_str(text)?;
+            }
+            s[text.len()..]
+                .chars()
+                .try_for_each(|c| self.pending.push(c, &mut self.inner))
+        }
+    }
+
+    // Held back whitespace, kept as runs of the same character so the usual spaces, tabs and
+    // newlines fit without allocating. Past that it goes into a `String` with `alloc`; without
+    // it the oldest run is written early, which only shows when all of it turns out to trail.
+    const PENDING_RUNS: usize = 8;
+
+    struct Pending {
+        runs: [(char, usize); PENDING_RUNS],
+        len: usize,
+        #[cfg(feature = "alloc")]
+        spilled: String,
+    }
+
+    impl Pending {
+        fn new() -> Self {
+            Self {
+                runs: [(' ', 0); PENDING_RUNS],
+                len: 0,
+                #[cfg(feature = "alloc")]
+                spilled: String::new(),
+            }
+        }
+
+        #[cfg_attr(feature = "alloc", allow(unused_variables))]
+        fn push<W: core::fmt::Write>(&mut self, c: char, writer: &mut W) -> core::fmt::Result {
+            #[cfg(feature = "alloc")]
+            if !self.spilled.is_empty() {
+                self.spilled.push(c);
+                return Ok(());
+            }
+            match self.runs[..self.len].last_mut() {
+                Some((last, count)) if *last == c => *count += 1,
+                _ if self.len < PENDING_RUNS => {
+                    self.runs[self.len] = (c, 1);
+                    self.len += 1;
+                }
+                #[cfg(feature = "alloc")]
+                _ => self.spilled.push(c),
+                #[cfg(not(feature = "alloc"))]
+                _ => {
+                    let (oldest, count) = self.runs[0];
+                    (0..count).try_for_each(|_| writer.write_char(oldest))?;
+                    self.runs.copy_within(1.., 0);
+                    self.runs[self.len - 1] = (c, 1);
+                }
+            }
+            Ok(())
+        }
+
+        fn flush<W: core::fmt::Write>(&mut self, writer: &mut W) -> core::fmt::Result {
+            for &(c, count) in &self.runs[..self.len] {
+                (0..count).try_for_each(|_| writer.write_char(c))?;
+            }
+            self.len = 0;
+            #[cfg(feature = "alloc")]
+            {
+                writer.write_str(&self.spilled)?;
+                self.spilled.clear();
+            }
+            Ok(())
+        }
+    }
+
+    /// The integer types `GroupDigits` can write, and references to them.
+    pub trait GroupedInteger: core::fmt::Display {}
+
+    macro_rules! grouped_integer {
+        ($($ty:ty),*) => {
+            $(impl GroupedInteger for $ty {})*
+        };
+    }
+
+    grouped_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    impl<T: GroupedInteger + ?Sized> GroupedInteger for &T {}
+
+    // A fixed size buffer for `GroupDigits`, so grouping never allocates
+    struct StackBuf<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> StackBuf<N> {
+        fn new() -> Self {
+            Self {
+                bytes: [0; N],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            // Only whole `str`s are ever written into it
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+        }
+    }
+
+    impl<const N: usize> core::fmt::Write for StackBuf<N> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    /// Writes an integer with its digits grouped in threes by commas, for
+    /// `#[display(group_digits(...))]`. The formatter's width, fill and sign flags apply to the
+    /// grouped number.
+    pub struct GroupDigits<T>(pub T);
+
+    impl<T: GroupedInteger> core::fmt::Display for GroupDigits<T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            use core::fmt::Write;
+
+            // Room for the 39 digits of `u128::MAX` and the sign of `i128::MIN`
+            let mut number = StackBuf::<40>::new();
+            write!(number, "{}", self.0)?;
+            let (nonnegative, digits) = match number.as_str().strip_prefix('-') {
+                Some(digits) => (false, digits),
+                None => (true, number.as_str()),
+            };
+            // And for a comma between every group of three
+            let mut grouped = StackBuf::<52>::new();
+            for (i, digit) in digits.char_indices() {
+                if i > 0 && (digits.len() - i) % 3 == 0 {
+                    grouped.write_char(',')?;
+                }
+                grouped.write_char(digit)?;
+            }
+            f.pad_integral(nonnegative, "", grouped.as_str())
+        }
+    }
+
+    /// Writes an integer field with `itoa`, for `#[enum_display(fast_numeric)]`.
+    #[cfg(feature = "fast_numeric")]
+    #[inline]
+    pub fn write_integer<I: itoa::Integer>(
+        f: &mut core::fmt::Formatter,
+        value: I,
+    ) -> core::fmt::Result {
+        f.write_str(itoa::Buffer::new().format(value))
+    }
+}
+
+// Expands to nothing when the `fast_numeric` feature is enabled, and to an error pointing at the
+// feature otherwise, since the derive can't see the features of this crate itself
+#[cfg(feature = "fast_numeric")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_fast_numeric {
+    () => {};
+}
+
+#[cfg(not(feature = "fast_numeric"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_fast_numeric {
+    () => {
+        ::core::compile_error!(
+            "`#[enum_display(fast_numeric)]` needs the `fast_numeric` feature of `enum-display`"
+        );
+    };
+}
+
+// Keeps the generated items that need `alloc` only when this crate's `alloc` feature is enabled,
+// the derive can't see the features of this crate itself
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:item)*) => {};
+}
+
+/// The error returned when parsing an enum that derives [`EnumFromStr`] or [`EnumTryFrom`] fails.
+///
+/// It keeps the input that didn't match when the `alloc` feature is enabled, and implements
+/// [`std::error::Error`] when the `std` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    #[cfg(feature = "alloc")]
+    input: String,
+}
+
+impl ParseEnumError {
+    /// Creates an error for a string that didn't match any variant.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn new(input: &str) -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            input: input.to_string(),
+        }
+    }
+
+    /// The string that didn't match any variant.
+    #[cfg(feature = "alloc")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unrecognized variant: {:?}", self.input)
+    }
+
+    // Without `alloc` there's nowhere to keep the input
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unrecognized variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEnumError {}
+
+/// Transforms a name the same way `#[enum_display(case = "...")]` transforms variant names, for
+/// strings that are only known at runtime.
+///
+/// `case` takes the same names as the attribute, including `FirstUpper` and `FirstLower`, and
+/// raw identifiers lose their `r#` prefix first. Returns `None` for an unknown case name.
+///
+/// ```rust
+/// use enum_display::transform_ident;
+///
+/// assert_eq!(transform_ident("LightRed", "Kebab").as_deref(), Some("light-red"));
+/// assert_eq!(transform_ident("r#Type", "Snake").as_deref(), Some("type"));
+/// assert_eq!(transform_ident("LightRed", "Shouting"), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn transform_ident(ident: &str, case: &str) -> Option<String> {
+    // The derive's own case tables, so the two can't drift apart
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    let case = enum_display_case::case_transform(case)?;
+    Some(enum_display_case::transform_case(
+        ident.to_string(),
+        Some(case),
+        None,
+    ))
+}
+
+// The unit tests lean on `String` and `to_string()` throughout
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnum {
+        Name,
+        Address {
+            street: String,
+            city: String,
+            state: String,
+            zip: String,
+        },
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Kebab")]
+    enum TestEnumWithAttribute {
+        Name,
+        Address {
+            street: String,
+            city: String,
+            state: String,
+            zip: String,
+        },
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithFormat {
+        #[display("{variant}!")]
+        Name,
+        #[display("{variant}: {street}, {city}")]
+        Address {
+            street: String,
+            city: String,
+            state: String,
+            zip: String,
+        },
+        #[display(format = "{variant} {0}/{1}/{2:04}")]
+        DateOfBirth(u32, u32, u32),
+        Unformatted,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Kebab")]
+    enum TestEnumWithVariantCase {
+        DefaultCase,
+        #[display(case = "ScreamingSnake")]
+        ProtocolConstant,
+        #[display("<{variant}>", case = "ScreamingSnake")]
+        FormattedConstant(u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Lower", prefix = "[", suffix = "]")]
+    enum TestEnumWithAffixes {
+        Name,
+        Address { street: String, city: String },
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(prefix = "{", suffix = "}")]
+    enum TestEnumWithFormatAndAffixes {
+        Name,
+        #[display("{variant}: {city}")]
+        Address {
+            street: String,
+            city: String,
+        },
+        #[display("{0}-{1}-{2}")]
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithTransparent {
+        Name,
+        #[display(transparent)]
+        Other(String),
+        #[display(transparent)]
+        Wrapped {
+            inner: u32,
+        },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake")]
+    enum TestEnumWithEnumName {
+        #[display("{enum}::{variant}")]
+        Name,
+        #[display("{enum}::{variant} in {city}")]
+        Address { street: String, city: String },
+        #[display("{enum:>22}/{0}")]
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithEscapedBraces {
+        #[display("{{{variant}}}")]
+        Name,
+        #[display("{{city}} is {city}}}")]
+        Address { city: String },
+        #[display("{{{0}}} {{1}} {{{{{1}}}}}")]
+        DateOfBirth(u32, u32),
+        #[display("{{enum}} {enum}")]
+        Empty,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithRawIdents {
+        r#Fn,
+        #[display("{variant}({0})")]
+        r#Type(u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Lower")]
+    enum TestEnumWithRawIdentsAndCase {
+        r#Fn,
+        r#Type { name: String },
+    }
+
+    #[derive(Debug, PartialEq, EnumDisplay, EnumTryFrom)]
+    #[enum_display(case = "Lower")]
+    enum TestEnumTryFrom {
+        Red,
+        Green,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = Kebab)]
+    enum TestEnumWithUnquotedCase {
+        Name,
+        Address {
+            street: String,
+            city: String,
+            state: String,
+            zip: String,
+        },
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = Case::Kebab)]
+    enum TestEnumWithCasePath {
+        DateOfBirth(u32, u32, u32),
+        #[display(case = ScreamingSnake)]
+        ProtocolConstant,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithFieldAliases {
+        #[display(fields("lat", "lon"), "{lat},{lon}")]
+        Coordinates(f64, f64),
+        #[display(fields(year, month, day), "{year}-{month:02}-{day:02} ({0})")]
+        Date(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(format = "<{variant}>")]
+    enum TestEnumWithDefaultFormat {
+        Name,
+        Address {
+            street: String,
+            city: String,
+        },
+        DateOfBirth(u32, u32, u32),
+        #[display("{variant}: {0}")]
+        Age(u32),
+        #[display("{variant} in {city}")]
+        City {
+            city: String,
+        },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(inline)]
+    enum TestEnumWithInline {
+        Name,
+        #[display("{variant}: {0}")]
+        Age(u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithBindings {
+        #[display(bind(sum = "x + y"), "sum is {sum}")]
+        Sum { x: i32, y: i32 },
+        #[display(
+            fields(w, h),
+            bind(area = "w * h", perimeter = "2 * (w + h)"),
+            "{area}/{perimeter}"
+        )]
+        Rectangle(u32, u32),
+        #[display(fields(w, h), bind(ratio = "*w as f64 / *h as f64"), "{ratio:.1}")]
+        Ratio(u32, u32),
+    }
+
+    #[derive(EnumDisplay)]
+    struct TestUnitStruct;
+
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake")]
+    struct TestUnitStructWithCase;
+
+    #[derive(EnumDisplay)]
+    #[display("{0}{1}")]
+    struct TestTupleStruct(f64, &'static str);
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[display("{variant} at {street}")]
+    struct TestNamedStruct {
+        street: String,
+        city: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    struct TestNamedStructWithoutFormat {
+        street: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "FirstLower")]
+    enum TestEnumWithFirstLower {
+        FooBar,
+        #[display(case = FirstUpper)]
+        #[allow(non_camel_case_types)]
+        fooBar,
+        #[display(case = "FirstUpper")]
+        HTTPServer,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(pad)]
+    enum TestEnumWithPad {
+        Name,
+        Address { street: String },
+        DateOfBirth(u32, u32, u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(pad)]
+    enum TestEnumWithPadAndFormat {
+        #[display("{variant}!")]
+        Name,
+        Unformatted,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithFormatSpecs {
+        #[display("{variant:>8}|{value:08}")]
+        Padded { value: i32 },
+        #[display("{variant:<6}|{ratio:.2}|{name:^7}")]
+        Precision { ratio: f64, name: &'static str },
+        #[display("{0:>5}|{1:+.1}|{0:#x}")]
+        Positional(u32, f32),
+        #[display("{enum:-<26}|{value:#06b}")]
+        Binary { value: u8 },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithVariantField {
+        #[display("{variant}: {kind}")]
+        #[display(bind(kind = "variant"))]
+        Named { variant: String },
+        #[display("{variant:>7} {0}")]
+        Padded { variant: String },
+    }
+
+    #[derive(EnumDisplay)]
+    enum TestEnumWithBuffer {
+        #[display("{variant} {buf}")]
+        Named { buf: u8 },
+    }
+
+    // Fields called `f` are bound next to the formatter the generated code writes to
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(debug_alternate, prefix = "<", suffix = ">")]
+    enum TestEnumWithFormatterField {
+        #[display("{variant} {f}")]
+        Named {
+            f: u8,
+        },
+        #[display(quote, "{f}")]
+        Quoted {
+            f: u8,
+        },
+        #[display(transparent)]
+        Transparent {
+            f: u8,
+        },
+        #[display(with = "fmt_pair")]
+        With {
+            f: u8,
+            g: u8,
+        },
+        Plain {
+            f: u8,
+        },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay, serde::Serialize)]
+    #[enum_display(use_serde_rename, case = "Kebab")]
+    enum TestEnumWithSerdeRename {
+        #[serde(rename = "first")]
+        FirstChoice,
+        #[serde(rename(serialize = "second", deserialize = "2"))]
+        SecondChoice,
+        #[serde(rename = "third")]
+        #[display("{variant}!")]
+        ThirdChoice,
+        #[serde(rename = "{braces}")]
+        Braces,
+        NotRenamed,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay, serde::Serialize)]
+    enum TestEnumWithoutSerdeRename {
+        #[serde(rename = "first")]
+        FirstChoice,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake", variant_case = "Title")]
+    enum TestEnumWithFormatVariantCase {
+        PlainName,
+        #[display("{variant}!")]
+        FormattedName,
+        #[display("{variant} ({0})")]
+        FormattedField(u32),
+        #[display(case = "Kebab", "{variant}?")]
+        OwnCase,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(
+        group(errors = "an error occurred", braces = "{}"),
+        prefix = "<",
+        suffix = ">"
+    )]
+    enum TestEnumWithGroups {
+        Ok,
+        #[display(group = "errors")]
+        NotFound,
+        #[display(group = "errors")]
+        Timeout(u32),
+        #[display(group = "errors")]
+        Denied {
+            reason: String,
+        },
+        #[display(group = "braces")]
+        Braces,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithNumericSpecs {
+        #[display("{0:#x} {0:X} {0:#o} {0:b}")]
+        Radix(u32),
+        #[display("{0:#010b}|{1:+}|{1:+05}")]
+        Padded(u8, i32),
+        #[display("{ 0 }|{ 1 :04}|{2: >4}")]
+        Spaces(u32, u32, u32),
+        #[display("{ variant }!")]
+        Shout,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithLast {
+        #[display("{0}..{last}")]
+        Range(u32, u32, u32),
+        #[display("{last:>3}")]
+        Single(u32),
+        #[display(bind(last = "\"bound\""), "{last}")]
+        Bound(u32, u32),
+    }
+
+    struct TestAlternate;
+
+    impl fmt::Display for TestAlternate {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(if f.alternate() { "alternate" } else { "plain" })
+        }
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithTransparentFlags {
+        #[display(transparent)]
+        Wrapper(TestAlternate),
+        #[display(transparent)]
+        Text { inner: &'static str },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(prefix = "<", suffix = ">")]
+    enum TestEnumWithTransparentAffixes {
+        #[display(transparent)]
+        Wrapper(TestAlternate),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(debug, case = "Snake")]
+    enum TestEnumWithDebug {
+        NotFound,
+        #[display("{variant}({0})")]
+        Code(u32),
+        Named {
+            field: u32,
+        },
+    }
+
+    // No bounds on the declaration, the derive adds the ones the Display impl needs
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestGenericEnum<T, U, V> {
+        #[display("{0}")]
+        Value(T),
+        #[display("{inner:?}/{hex:x}")]
+        Named {
+            inner: Option<U>,
+            hex: V,
+        },
+        #[display(transparent)]
+        Forward(T),
+        Empty,
+    }
+
+    // Holding a `Label<U>` needs `U: Into<String>` and `&'static T` needs `T: 'static`, so this
+    // only compiles when the where clause is copied to the impls
+    struct Label<U: Into<String>>(U);
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestGenericEnumWithWhere<T, U>
+    where
+        T: core::fmt::Display + 'static,
+        U: Into<String> + Clone,
+    {
+        #[display("{0}")]
+        Static(&'static T),
+        #[display(bind(label = "Into::<String>::into(label.0.clone())"), "[{label}]")]
+        Labelled { label: Label<U> },
+        #[display("{0}={1:?}")]
+        Pair(T, Option<T>),
+    }
+
+    #[derive(EnumDisplay)]
+    #[display("{value} {unit}")]
+    struct TestGenericStruct<T> {
+        value: T,
+        unit: &'static str,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestGenericEnumWithLifetime<'a, T: Copy> {
+        #[display("{0}")]
+        Borrowed(&'a str),
+        Unused(core::marker::PhantomData<T>),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithQuote {
+        #[display(quote)]
+        Plain,
+        #[display(quote, "{variant} \"{0}\"")]
+        Formatted(&'static str),
+        #[display(quote, transparent)]
+        Inner(&'static str),
+        #[display(quote, case = "Lower", "{variant}\\")]
+        Backslash,
+        Unquoted,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(quote, prefix = "<", suffix = ">")]
+    enum TestEnumWithEnumQuote {
+        Plain,
+        #[display("say {0}")]
+        Formatted(&'static str),
+    }
+
+    const BASE_CODE: i32 = 10;
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[repr(i32)]
+    enum TestEnumWithDiscriminants {
+        #[display("{discriminant:03}")]
+        First,
+        #[display("{variant}={discriminant}")]
+        Second = 5,
+        #[display("{variant}={discriminant}")]
+        Third,
+        #[display("{variant}={discriminant:#x}")]
+        Fourth = BASE_CODE * 3,
+        #[display("{variant}={discriminant}")]
+        Fifth,
+        #[display("{variant}={discriminant}")]
+        Negative = -2,
+        #[display("{variant}={discriminant}")]
+        AfterNegative,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithDiscriminantField {
+        #[display("{discriminant}")]
+        Tagged { discriminant: &'static str },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(prefix = "{", suffix = "}")]
+    enum TestEnumWithJoin {
+        #[display(join = " | ")]
+        Triple(&'static str, u32, char),
+        #[display(join = "}{")]
+        Braces(u8, u8),
+        #[display(join = ", ")]
+        Empty(),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(no_coverage, debug)]
+    enum TestEnumWithoutCoverage {
+        Plain,
+        #[display("{variant} {0}")]
+        Formatted(u8),
+    }
+
+    fn fmt_area(f: &mut core::fmt::Formatter, width: &u32, height: &u32) -> core::fmt::Result {
+        write!(f, "{}x{} ({})", width, height, width * height)
+    }
+
+    fn fmt_pair(f: &mut core::fmt::Formatter, a: &u8, b: &u8) -> core::fmt::Result {
+        write!(f, "{}~{}", a, b)
+    }
+
+    fn fmt_nothing(f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("nothing")
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(prefix = "<", suffix = ">")]
+    enum TestEnumWithFunctions {
+        #[display(with = "fmt_area")]
+        Area {
+            width: u32,
+            height: u32,
+        },
+        #[display(with = "self::fmt_pair")]
+        Pair(u8, u8),
+        #[display(with = "fmt_nothing")]
+        Nothing,
+        Plain,
+    }
+
+    #[derive(EnumDisplay, EnumFromStr)]
+    #[enum_display(debug)]
+    enum TestEmptyEnum {}
+
+    #[allow(dead_code, non_snake_case)]
+    #[derive(EnumDisplay)]
+    #[enum_display(rename_all_fields = "Snake")]
+    enum TestEnumWithRenamedFields {
+        #[display("{first_name} {lastName}, {user_id:04}")]
+        User {
+            firstName: &'static str,
+            lastName: &'static str,
+            userId: u32,
+        },
+        #[display("{plain}")]
+        Plain { plain: u8 },
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+    #[enum_display(case = "Lower")]
+    enum TestEnumWithCfg {
+        #[cfg(feature = "std")]
+        #[display("with std")]
+        WithStd,
+        #[display("without std")]
+        #[cfg(not(feature = "std"))]
+        WithoutStd,
+        #[cfg(any())]
+        #[display("never {0}")]
+        Never(u8),
+        #[cfg_attr(feature = "std", display("std {variant}"))]
+        #[cfg_attr(not(feature = "std"), display("no_std {variant}"))]
+        Either,
+        Always,
+    }
+
+    #[allow(dead_code)]
+    #[doc = concat!("Attributes that ", "aren't plain meta items")]
+    #[derive(EnumDisplay, serde::Serialize)]
+    #[serde(rename_all = "UPPERCASE", bound(serialize = "T: serde::Serialize"))]
+    #[enum_display(case = "Lower", use_serde_rename)]
+    enum TestEnumWithOtherAttributes<T> {
+        #[doc = concat!("A ", "variant")]
+        #[serde(rename = "first", alias = "one")]
+        First,
+        #[rustfmt::skip]
+        #[display("{variant}: {0}")]
+        Second(T),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(qualified, case = "Snake")]
+    enum TestEnumQualified {
+        LightRed,
+        #[display("{variant}({0})")]
+        Custom(u8),
+        #[display("{enum} only")]
+        Unqualified,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithDebugSpecs {
+        #[display("{0:?} and {1:?}")]
+        Debug(&'static str, Option<u8>),
+        #[display("{0:#?}")]
+        Pretty((u8, u8)),
+        #[display("{ 0 :?}")]
+        Spaced(char),
+        #[display("{name:?}")]
+        Named { name: &'static str },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Kebab")]
+    enum TestEnumWithLetterCase {
+        LightRed,
+        #[display(uppercase)]
+        DarkGreen,
+        #[display(uppercase, "{variant} {0}")]
+        Named(&'static str),
+        #[display(lowercase, quote, "{0}")]
+        Quoted(&'static str),
+        #[display(uppercase, transparent)]
+        Forwarded(&'static str),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake", prefix = "<", suffix = ">")]
+    enum TestEnumWithTrailingCommas {
+        LightRed,
+        #[display("{variant} {0}")]
+        DarkGreen(u8),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithPositionalNamedFields {
+        #[display("{variant}: {1}, {0:>4}")]
+        Pair { first: u8, second: &'static str },
+        #[display("{0:?} is {first}")]
+        Mixed { first: char },
+        #[display("{0} {street}, {city} {1:>0$}")]
+        Address { street: usize, city: &'static str },
+        #[display(bind(city = "city.len()"), "{city} letters in {1}")]
+        Shadowed {
+            street: &'static str,
+            city: &'static str,
+        },
+        #[display("{variant}-{0}")]
+        Variant { variant: &'static str },
+        #[display("{0} of {enum}")]
+        Enum { r#enum: u8 },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(strip_prefix = "Http", strip_suffix = "Error", case = "Kebab")]
+    enum TestEnumWithStrippedNames {
+        HttpNotFoundError,
+        HttpTimeout,
+        ParseError,
+        Unrelated,
+        Error,
+        #[display("{variant} ({0})")]
+        HttpStatusError(u16),
+    }
+
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+    #[enum_display(case = "Snake")]
+    enum TestEnumWithDefault {
+        LogLevel,
+        #[display(default, "warn")]
+        Warning,
+        Error,
+    }
+
+    // The derive always expands in the crate defining the enum, where `#[non_exhaustive]` doesn't
+    // apply, so the generated matches need no wildcard arm
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+    #[non_exhaustive]
+    #[enum_display(case = "Lower")]
+    enum TestNonExhaustiveEnum {
+        Known,
+        #[non_exhaustive]
+        Later,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[non_exhaustive]
+    enum TestNonExhaustiveFields {
+        #[non_exhaustive]
+        #[display("{variant} {id}")]
+        Record { id: u32 },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithDebugFields<T> {
+        #[display(debug_fields)]
+        Tuple(u8, &'static str, T),
+        #[display(debug_fields)]
+        Named {
+            id: u32,
+            r#type: char,
+        },
+        #[display(debug_fields)]
+        Unit,
+        Plain(u8),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(debug_fields, case = "Snake")]
+    enum TestEnumWithDefaultDebugFields {
+        EmptyName,
+        Single(Option<u8>),
+        #[display("{variant} #{0}")]
+        Custom(u8),
+    }
+
+    // Deliberately neither `Copy` nor `Clone`, so formatting it must go through a reference
+    #[derive(Debug)]
+    struct NotClone(String);
+
+    impl core::fmt::Display for NotClone {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    fn fmt_not_clone(f: &mut core::fmt::Formatter, value: &NotClone) -> core::fmt::Result {
+        write!(f, "with {}", value)
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithBorrowedFields {
+        #[display("{variant}: {names:?} {owner}")]
+        Named { names: Vec<String>, owner: NotClone },
+        #[display("{0} and {1:?}")]
+        Unnamed(NotClone, Vec<String>),
+        #[display(join = "/")]
+        Joined(NotClone, NotClone),
+        #[display(transparent)]
+        Transparent(NotClone),
+        #[display(debug_fields)]
+        Debugged(NotClone),
+        #[display(with = "fmt_not_clone")]
+        With(NotClone),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(ascii_only, case = "Kebab", prefix = "<", suffix = ">")]
+    enum TestEnumAsciiOnly {
+        LogIn,
+        #[display("{variant} as {0}")]
+        LogInAs(&'static str),
+        #[display(join = "; ")]
+        Joined(u8, u8),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake", prefix = "<", suffix = ">")]
+    enum TestEnumCStr {
+        FirstName,
+        #[display("{variant}!")]
+        Loud,
+        #[display(quote)]
+        Quoted,
+        #[display(with = "fmt_nothing")]
+        WithFunction,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithDedent {
+        #[display(
+            dedent,
+            "
+            {variant}:
+                {0}
+            end"
+        )]
+        Block(u8),
+        #[display(
+            dedent,
+            "first
+            second"
+        )]
+        FirstLine,
+        #[display(
+            "
+            {variant}"
+        )]
+        Kept,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(
+        dedent,
+        format = "
+        <{variant}>
+        "
+    )]
+    enum TestEnumWithDefaultDedent {
+        Plain,
+        #[display(
+            "
+            {variant}
+              {0}"
+        )]
+        Nested(u8),
+    }
+
+    // Writes through `write_display` and nothing else, so it never touches the derived `Display`
+    struct WriteDisplay<'a, T>(
+        &'a T,
+        fn(&T, &mut core::fmt::Formatter) -> core::fmt::Result,
+    );
+
+    impl<T> core::fmt::Display for WriteDisplay<'_, T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            (self.1)(self.0, f)
+        }
+    }
+
+    /// An enum whose docs mention #[display("{variant}")] and #[enum_display(case = "Upper")]
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Kebab")]
+    enum TestEnumWithDocs {
+        /// The plain variant, displayed as `{variant}` with no braces of its own
+        PlainName,
+        /// Uses #[display("...")] with `{0}`, which shouldn't be read from this comment
+        #[display("{variant} {0}")]
+        /// A second doc line after the display attribute {unknown}
+        WithField(u8),
+        #[doc = "display"]
+        #[doc = "{{ unbalanced }"]
+        Documented { id: u8 },
+    }
+
+    const TEST_VERSION: &str = "1.2";
+    static TEST_BUILD: u32 = 7;
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithExterns {
+        #[display(extern(TEST_VERSION), "{variant} v{TEST_VERSION}")]
+        Release,
+        #[display(
+            extern(TEST_VERSION, "TEST_BUILD"),
+            "{0} v{TEST_VERSION}+{TEST_BUILD:03}"
+        )]
+        Build(&'static str),
+        #[display(bind(max = "Self::MAX"), "{variant} up to {max}")]
+        Limited { current: u8 },
+    }
+
+    impl TestEnumWithExterns {
+        const MAX: u8 = 10;
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake")]
+    enum TestEnumWithDefaultBoundaries {
+        XMLHttpRequest,
+        HTTPSConnection,
+        Http2Server,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake", boundaries = "aA:1A")]
+    enum TestEnumWithBoundaries {
+        XMLHttpRequest,
+        HTTPSConnection,
+        Http2Server,
+        #[display("{variant}")]
+        Formatted,
+    }
+
+    #[allow(dead_code, non_snake_case)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Kebab", boundaries = "", rename_all_fields = "Snake")]
+    enum TestEnumWithoutBoundaries {
+        LongName,
+        #[display("{variant} {maxsize}")]
+        Sized {
+            maxSize: u8,
+        },
+    }
+
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr, EnumTryFrom)]
+    #[enum_display(case = "Lower", parse_case_insensitive)]
+    enum TestEnumCaseInsensitive {
+        Red,
+        #[display("Light Blue")]
+        LightBlue,
+        #[display("Éclair")]
+        Eclair,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(unique, case = "Lower")]
+    enum TestEnumUnique {
+        Kilobyte,
+        #[display("{variant}")]
+        Megabyte,
+        // Runtime output isn't compared, even when it could match another variant
+        #[display("kilo{0}")]
+        Kilo(&'static str),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Lower")]
+    enum TestEnumWithVariantEnumDisplay {
+        #[enum_display(as = "Custom")]
+        Renamed,
+        #[enum_display(as = "{variant} {0}", uppercase)]
+        Formatted(u8),
+        #[enum_display(case = "Kebab")]
+        KebabCase,
+        #[enum_display(quote)]
+        #[display(case = "Upper")]
+        Mixed,
+    }
+
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Upper")]
+    #[display("{variant}: {0}")]
+    struct TestStructWithEnumDisplay(u8);
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(format = "{variant}/{arity}")]
+    enum TestEnumWithArity {
+        Unit,
+        Address {
+            street: String,
+            city: String,
+            state: String,
+            zip: String,
+        },
+        DateOfBirth(u32, u32, u32),
+        #[display("{variant} {arity:>3}")]
+        Padded(u8),
+        // A field with the name is what `{arity}` refers to instead
+        #[display("{variant} {arity}")]
+        Shadowed {
+            arity: &'static str,
+        },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(debug_alternate, case = "Snake", prefix = "[", suffix = "]")]
+    enum TestEnumWithDebugAlternate {
+        #[display("{variant} {street}")]
+        Address {
+            street: &'static str,
+            r#type: char,
+        },
+        Pair(u8, Option<u8>),
+        EmptyName,
+        #[display(transparent)]
+        Inner(&'static str),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(debug_alternate, quote, pad, prefix = "<", suffix = ">")]
+    enum TestEnumWithQuotedDebugAlternate {
+        Fields { x: u8 },
+        Empty,
+    }
+
+    #[derive(EnumDisplay)]
+    #[enum_display(debug_alternate)]
+    enum TestEnumWithGenericDebugAlternate<T> {
+        A(T),
+        B { x: T },
+        C,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(json, case = "Snake")]
+    enum TestEnumJson {
+        RedApple,
+        #[display("say \"hi\"\nthen\tleave\u{1}")]
+        Escaped,
+        #[display("{variant}: {0}")]
+        Formatted(&'static str),
+        #[display(transparent)]
+        Inner(&'static str),
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, EnumDisplay)]
+    #[repr(u16)]
+    enum TestEnumFromRepr {
+        Continue = 100,
+        SwitchingProtocols,
+        Ok = 200,
+        Redirect(&'static str) = 300,
+        NotFound = 404,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithWidthFields {
+        #[display("[{0:1$}]")]
+        Padded(&'static str, usize),
+        #[display("[{0:>1$.2$}]")]
+        Truncated(&'static str, usize, usize),
+        #[display("[{value:0width$}]")]
+        Named { value: u32, width: usize },
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithCast {
+        #[display(cast = "u64", fields(level), bind(scaled = "level * 1000"), "{scaled}")]
+        Scaled(u8),
+        #[display(cast = i32, "{0}-{1}={last}")]
+        Difference(u8, u8),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Snake")]
+    enum TestEnumWithAllFields {
+        #[display(all_fields)]
+        Person {
+            name: &'static str,
+            age: u32,
+            r#type: char,
+        },
+        #[display(all_fields = " | ")]
+        Separated { low: f32, high: f32 },
+        #[display(all_fields = "}{")]
+        Braces { a: u8, b: u8 },
+        #[display(all_fields)]
+        Nothing {},
+    }
+
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+    #[enum_display(case = "Snake")]
+    enum TestEnumWithSymbolicOutput {
+        #[display(Red)]
+        LightRed,
+        #[display(Palette::DarkBlue)]
+        DarkBlue,
+        #[display(r#Green, case = "Upper")]
+        Green,
+        Unchanged,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithSelfBinding {
+        #[display(bind(total = "self.total()"), "{0}+{1}={total}")]
+        Sum(u32, u32),
+        #[display(bind(kind = "self.kind()"), "{kind} {name}")]
+        Named { name: &'static str },
+        #[display(bind(kind = "self.kind()"), "{kind}")]
+        Unit,
+    }
+
+    impl TestEnumWithSelfBinding {
+        fn total(&self) -> u32 {
+            match self {
+                Self::Sum(a, b) => a + b,
+                _ => 0,
+            }
+        }
+
+        fn kind(&self) -> &'static str {
+            match self {
+                Self::Sum(..) => "sum",
+                Self::Named { .. } => "named",
+                Self::Unit => "unit",
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Upper", prefix = " <", suffix = "> ")]
+    enum TestEnumWithTrim {
+        #[display(trim, "{0}")]
+        Padded(&'static str),
+        #[display(trim, "  {variant} {name:>6} ")]
+        Named {
+            name: &'static str,
+        },
+        #[display(trim, quote, " {0} ")]
+        Quoted(&'static str),
+        #[display(trim, "  {variant}  ")]
+        Fixed,
+        #[display(trim, "{0}")]
+        Blank(&'static str),
+        Untrimmed,
+    }
+
+    // Writes its text in pieces, and counts how often it's formatted
+    struct Chunked(std::cell::Cell<usize>);
+
+    impl fmt::Display for Chunked {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            for piece in ["  ", "ab", " ", "\t", "cd", " \n", "\t "] {
+                f.write_str(piece)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(EnumDisplay)]
+    enum TestEnumWithTrimmedPieces {
+        #[display(trim, "{0}")]
+        Chunks(Chunked),
+        #[display(trim, "{0}")]
+        Text(&'static str),
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithTruncate {
+        #[display(truncate = 7, "{0}")]
+        Formatted(&'static str),
+        #[display(truncate = 5)]
+        VeryLongName,
+        #[display(truncate = 3, "日本語のテキスト")]
+        Fixed,
+        #[display(truncate = 4, quote, uppercase, "{0}")]
+        Quoted(&'static str),
+        #[display(truncate = 3, transparent)]
+        Transparent(u32),
+        #[display(truncate = 4, trim, "  {0}  ")]
+        Trimmed(&'static str),
+        #[display(truncate = 5)]
+        Exact,
+    }
+
+    // Only implements Debug, so the fields below can't be written with Display
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct DebugOnly(u8);
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(field_repr = "debug")]
+    enum TestEnumWithDebugFieldRepr {
+        #[display("{variant}: {0}")]
+        Tuple(DebugOnly),
+        #[display("{variant}: {inner} {name:>4} {name:?}")]
+        Named {
+            inner: DebugOnly,
+            name: &'static str,
+        },
+        #[display(fields(label, value), "{label}, {last}")]
+        Aliased(&'static str, DebugOnly),
+        #[display(bind(len = "text.len()"), "{text} ({len})")]
+        Bound {
+            text: &'static str,
+        },
+        #[display(join = " ")]
+        Joined(DebugOnly, char),
+        #[display(field_repr = "display", "{0} {{0}}")]
+        Displayed(&'static str),
+        Unit,
+    }
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    enum TestEnumWithGroupedDigits {
+        #[display(group_digits(0), "{0} items")]
+        Count(u64),
+        #[display(group_digits(0), "[{0:>10}]")]
+        Change(i64),
+        #[display(group_digits(total), "{0} over {count}, {total}")]
+        Named { total: i128, count: u32 },
+        #[display(fields(bytes), group_digits(bytes), "{bytes} B, {last}")]
+        Aliased(usize),
+        #[display(group_digits(x), bind(double = "x * 2"), "{x} -> {double}")]
+        Bound { x: u32 },
+        #[display(cast = "u64", group_digits(0, 1), "{0}/{1}")]
+        Cast(u8, u16),
+    }
+
+    #[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+    #[enum_display(case = "Kebab")]
+    enum TestEnumFromStr {
+        LightRed,
+        DarkGreen,
+        #[display("{variant}!")]
+        DeepBlue,
+    }
+
+    #[test]
+    fn test_unit_field_variant() {
+        assert_eq!(TestEnum::Name.to_string(), "Name");
+    }
+
+    #[test]
+    fn test_named_fields_variant() {
+        assert_eq!(
+            TestEnum::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+                state: "CA".to_string(),
+                zip: "12345".to_string()
+            }
+            .to_string(),
+            "Address"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant() {
+        assert_eq!(TestEnum::DateOfBirth(1, 1, 2000).to_string(), "DateOfBirth");
+    }
+
+    #[test]
+    fn test_unit_field_variant_case_transform() {
+        assert_eq!(TestEnumWithAttribute::Name.to_string(), "name");
+    }
+
+    #[test]
+    fn test_named_fields_variant_case_transform() {
+        assert_eq!(
+            TestEnumWithAttribute::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+                state: "CA".to_string(),
+                zip: "12345".to_string()
+            }
+            .to_string(),
+            "address"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_case_transform() {
+        assert_eq!(
+            TestEnumWithAttribute::DateOfBirth(1, 1, 2000).to_string(),
+            "date-of-birth"
+        );
+    }
+
+    #[test]
+    fn test_unit_field_variant_format() {
+        assert_eq!(TestEnumWithFormat::Name.to_string(), "Name!");
+    }
+
+    #[test]
+    fn test_named_fields_variant_format() {
+        assert_eq!(
+            TestEnumWithFormat::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+                state: "CA".to_string(),
+                zip: "12345".to_string()
+            }
+            .to_string(),
+            "Address: 123 Main St, Any Town"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_format() {
+        assert_eq!(
+            TestEnumWithFormat::DateOfBirth(1, 1, 2000).to_string(),
+            "DateOfBirth 1/1/2000"
+        );
+    }
+
+    #[test]
+    fn test_unformatted_variant_in_formatted_enum() {
+        assert_eq!(TestEnumWithFormat::Unformatted.to_string(), "Unformatted");
+    }
+
+    #[test]
+    fn test_variant_case_falls_back_to_enum_case() {
+        assert_eq!(
+            TestEnumWithVariantCase::DefaultCase.to_string(),
+            "default-case"
+        );
+    }
+
+    #[test]
+    fn test_variant_case_overrides_enum_case() {
+        assert_eq!(
+            TestEnumWithVariantCase::ProtocolConstant.to_string(),
+            "PROTOCOL_CONSTANT"
+        );
+    }
+
+    #[test]
+    fn test_variant_case_with_format() {
+        assert_eq!(
+            TestEnumWithVariantCase::FormattedConstant(1).to_string(),
+            "<FORMATTED_CONSTANT>"
+        );
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for value in [
+            TestEnumFromStr::LightRed,
+            TestEnumFromStr::DarkGreen,
+            TestEnumFromStr::DeepBlue,
+        ] {
+            assert_eq!(value.to_string().parse::<TestEnumFromStr>(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_from_str_transformed_names() {
+        assert_eq!(
+            "light-red".parse::<TestEnumFromStr>(),
+            Ok(TestEnumFromStr::LightRed)
+        );
+        assert_eq!(
+            "deep-blue!".parse::<TestEnumFromStr>(),
+            Ok(TestEnumFromStr::DeepBlue)
+        );
+    }
+
+    #[test]
+    fn test_from_str_unrecognized() {
+        let error = "LightRed".parse::<TestEnumFromStr>().unwrap_err();
+        assert_eq!(error, ParseEnumError::new("LightRed"));
+        assert_eq!(error.input(), "LightRed");
+        assert_eq!(error.to_string(), "unrecognized variant: \"LightRed\"");
+    }
+
+    #[test]
+    fn test_unit_field_variant_padding() {
+        assert_eq!(format!("{:>8}", TestEnumWithPad::Name), "    Name");
+        assert_eq!(format!("{:<8}|", TestEnumWithPad::Name), "Name    |");
+        assert_eq!(format!("{:*^8}", TestEnumWithPad::Name), "**Name**");
+    }
+
+    #[test]
+    fn test_fields_variant_padding() {
+        assert_eq!(
+            format!("{:>14}", TestEnumWithPad::DateOfBirth(1, 1, 2000)),
+            "   DateOfBirth"
+        );
+        assert_eq!(
+            format!(
+                "{:>8}",
+                TestEnumWithPad::Address {
+                    street: "123 Main St".to_string(),
+                }
+            ),
+            " Address"
+        );
+    }
+
+    #[test]
+    fn test_unformatted_variant_padding_in_formatted_enum() {
+        assert_eq!(
+            format!("{:>12}", TestEnumWithPadAndFormat::Unformatted),
+            " Unformatted"
+        );
+        assert_eq!(format!("{:>12}", TestEnumWithPadAndFormat::Name), "Name!");
+    }
+
+    #[test]
+    fn test_padding_is_opt_in() {
+        assert_eq!(format!("{:>6}", TestEnum::Name), "Name");
+        assert_eq!(format!("{:>6}", TestEnumWithPad::Name), "  Name");
+        assert_eq!(
+            format!("{:>6}", TestEnumWithFormat::Unformatted),
+            "Unformatted"
+        );
+        assert_eq!(format!("{:>6}|", TestEnumWithFormat::Name), "Name!|");
+    }
+
+    #[test]
+    fn test_unit_field_variant_affixes() {
+        assert_eq!(TestEnumWithAffixes::Name.to_string(), "[name]");
+        assert_eq!(TestEnumWithFormatAndAffixes::Name.to_string(), "{Name}");
+    }
+
+    #[test]
+    fn test_named_fields_variant_affixes() {
+        assert_eq!(
+            TestEnumWithAffixes::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+            }
+            .to_string(),
+            "[address]"
+        );
+        assert_eq!(
+            TestEnumWithFormatAndAffixes::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+            }
+            .to_string(),
+            "{Address: Any Town}"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_affixes() {
+        assert_eq!(
+            TestEnumWithAffixes::DateOfBirth(1, 1, 2000).to_string(),
+            "[date of birth]"
+        );
+        assert_eq!(
+            TestEnumWithFormatAndAffixes::DateOfBirth(1, 1, 2000).to_string(),
+            "{1-1-2000}"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_transparent() {
+        assert_eq!(
+            TestEnumWithTransparent::Other("Something else".to_string()).to_string(),
+            "Something else"
+        );
+    }
+
+    #[test]
+    fn test_named_fields_variant_transparent() {
+        assert_eq!(
+            TestEnumWithTransparent::Wrapped { inner: 42 }.to_string(),
+            "42"
+        );
+    }
+
+    #[test]
+    fn test_unit_field_variant_in_transparent_enum() {
+        assert_eq!(TestEnumWithTransparent::Name.to_string(), "Name");
+    }
+
+    #[test]
+    fn test_unit_field_variant_enum_name() {
+        assert_eq!(
+            TestEnumWithEnumName::Name.to_string(),
+            "TestEnumWithEnumName::name"
+        );
+    }
+
+    #[test]
+    fn test_named_fields_variant_enum_name() {
+        assert_eq!(
+            TestEnumWithEnumName::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+            }
+            .to_string(),
+            "TestEnumWithEnumName::address in Any Town"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_enum_name() {
+        assert_eq!(
+            TestEnumWithEnumName::DateOfBirth(1, 1, 2000).to_string(),
+            "  TestEnumWithEnumName/1"
+        );
+    }
+
+    #[test]
+    fn test_unit_field_variant_escaped_braces() {
+        assert_eq!(TestEnumWithEscapedBraces::Name.to_string(), "{Name}");
+        assert_eq!(
+            TestEnumWithEscapedBraces::Empty.to_string(),
+            "{enum} TestEnumWithEscapedBraces"
+        );
+    }
+
+    #[test]
+    fn test_named_fields_variant_escaped_braces() {
+        assert_eq!(
+            TestEnumWithEscapedBraces::Address {
+                city: "Any Town".to_string()
+            }
+            .to_string(),
+            "{city} is Any Town}"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_escaped_braces() {
+        assert_eq!(
+            TestEnumWithEscapedBraces::DateOfBirth(1, 2000).to_string(),
+            "{1} {1} {{2000}}"
+        );
+    }
+
+    #[test]
+    fn test_variant_name_in_const() {
+        const NAME: &str = TestEnum::Name.variant_name();
+        const TRANSFORMED: &str = TestEnumWithAttribute::DateOfBirth(1, 1, 2000).variant_name();
+        assert_eq!(NAME, "Name");
+        assert_eq!(TRANSFORMED, "date-of-birth");
+    }
+
+    #[test]
+    fn test_variant_name_matches_static_display() {
+        assert_eq!(TestEnumWithFormat::Name.variant_name(), "Name!");
+        assert_eq!(TestEnumWithAffixes::Name.variant_name(), "[name]");
+        assert_eq!(TestEnumFromStr::DeepBlue.variant_name(), "deep-blue!");
+    }
+
+    #[test]
+    fn test_variant_name_falls_back_for_formatted_variants() {
+        assert_eq!(
+            TestEnumWithFormat::DateOfBirth(1, 1, 2000).variant_name(),
+            "DateOfBirth"
+        );
+        assert_eq!(
+            TestEnumWithTransparent::Other("Something else".to_string()).variant_name(),
+            "Other"
+        );
+    }
+
+    #[test]
+    fn test_raw_ident_variant() {
+        assert_eq!(TestEnumWithRawIdents::r#Fn.to_string(), "Fn");
+        assert_eq!(TestEnumWithRawIdents::r#Type(1).to_string(), "Type(1)");
+    }
+
+    #[test]
+    fn test_raw_ident_variant_case_transform() {
+        assert_eq!(TestEnumWithRawIdentsAndCase::r#Fn.to_string(), "fn");
+        assert_eq!(
+            TestEnumWithRawIdentsAndCase::r#Type {
+                name: "Any".to_string()
+            }
+            .to_string(),
+            "type"
+        );
+    }
+
+    #[test]
+    fn test_try_from_transformed_name() {
+        assert_eq!(TestEnumTryFrom::try_from("red"), Ok(TestEnumTryFrom::Red));
+        assert_eq!(
+            TestEnumTryFrom::try_from("green"),
+            Ok(TestEnumTryFrom::Green)
+        );
+    }
+
+    #[test]
+    fn test_try_from_unrecognized() {
+        assert_eq!(
+            TestEnumTryFrom::try_from("Red"),
+            Err(ParseEnumError::new("Red"))
+        );
+        assert_eq!(
+            TestEnumTryFrom::try_from(" red"),
+            Err(ParseEnumError::new(" red"))
+        );
+        assert_eq!(
+            TestEnumTryFrom::try_from("red "),
+            Err(ParseEnumError::new("red "))
+        );
+    }
+
+    #[test]
+    fn test_unquoted_case_matches_quoted_case() {
+        assert_eq!(
+            TestEnumWithUnquotedCase::Name.to_string(),
+            TestEnumWithAttribute::Name.to_string()
+        );
+        assert_eq!(
+            TestEnumWithUnquotedCase::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+                state: "CA".to_string(),
+                zip: "12345".to_string()
+            }
+            .to_string(),
+            "address"
+        );
+        assert_eq!(
+            TestEnumWithUnquotedCase::DateOfBirth(1, 1, 2000).to_string(),
+            TestEnumWithAttribute::DateOfBirth(1, 1, 2000).to_string()
+        );
+    }
+
+    #[test]
+    fn test_case_path() {
+        assert_eq!(
+            TestEnumWithCasePath::DateOfBirth(1, 1, 2000).to_string(),
+            "date-of-birth"
+        );
+        assert_eq!(
+            TestEnumWithCasePath::ProtocolConstant.to_string(),
+            "PROTOCOL_CONSTANT"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_aliases() {
+        assert_eq!(
+            TestEnumWithFieldAliases::Coordinates(51.5, -0.125).to_string(),
+            "51.5,-0.125"
+        );
+        assert_eq!(
+            TestEnumWithFieldAliases::Date(2000, 1, 2).to_string(),
+            "2000-01-02 (2000)"
+        );
+    }
+
+    #[test]
+    fn test_default_format() {
+        assert_eq!(TestEnumWithDefaultFormat::Name.to_string(), "<Name>");
+        assert_eq!(
+            TestEnumWithDefaultFormat::Address {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+            }
+            .to_string(),
+            "<Address>"
+        );
+        assert_eq!(
+            TestEnumWithDefaultFormat::DateOfBirth(1, 1, 2000).to_string(),
+            "<DateOfBirth>"
+        );
+    }
+
+    #[test]
+    fn test_default_format_overridden_by_variant() {
+        assert_eq!(TestEnumWithDefaultFormat::Age(30).to_string(), "Age: 30");
+        assert_eq!(
+            TestEnumWithDefaultFormat::City {
+                city: "Any Town".to_string()
+            }
+            .to_string(),
+            "City in Any Town"
+        );
+    }
+
+    #[test]
+    fn test_inline() {
+        assert_eq!(TestEnumWithInline::Name.to_string(), "Name");
+        assert_eq!(TestEnumWithInline::Age(30).to_string(), "Age: 30");
+    }
+
+    #[test]
+    fn test_named_fields_variant_bindings() {
+        assert_eq!(
+            TestEnumWithBindings::Sum { x: 2, y: 3 }.to_string(),
+            "sum is 5"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_fields_variant_bindings() {
+        assert_eq!(TestEnumWithBindings::Rectangle(2, 3).to_string(), "6/10");
+        assert_eq!(TestEnumWithBindings::Ratio(3, 2).to_string(), "1.5");
+    }
+
+    #[test]
+    fn test_unit_struct() {
+        assert_eq!(TestUnitStruct.to_string(), "TestUnitStruct");
+        assert_eq!(
+            TestUnitStructWithCase.to_string(),
+            "test_unit_struct_with_case"
+        );
+    }
+
+    #[test]
+    fn test_tuple_struct_format() {
+        assert_eq!(TestTupleStruct(1.5, "m").to_string(), "1.5m");
+    }
+
+    #[test]
+    fn test_named_struct() {
+        assert_eq!(
+            TestNamedStruct {
+                street: "123 Main St".to_string(),
+                city: "Any Town".to_string(),
+            }
+            .to_string(),
+            "TestNamedStruct at 123 Main St"
+        );
+        assert_eq!(
+            TestNamedStructWithoutFormat {
+                street: "123 Main St".to_string(),
+            }
+            .to_string(),
+            "TestNamedStructWithoutFormat"
+        );
+    }
+
+    #[test]
+    fn test_first_char_cases() {
+        assert_eq!(TestEnumWithFirstLower::FooBar.to_string(), "fooBar");
+        assert_eq!(TestEnumWithFirstLower::fooBar.to_string(), "FooBar");
+        assert_eq!(TestEnumWithFirstLower::HTTPServer.to_string(), "HTTPServer");
+    }
+
+    #[test]
+    fn test_named_field_format_specs() {
+        assert_eq!(
+            TestEnumWithFormatSpecs::Padded { value: -42 }.to_string(),
+            "  Padded|-0000042"
+        );
+        assert_eq!(
+            TestEnumWithFormatSpecs::Precision {
+                ratio: 0.125,
+                name: "abc",
+            }
+            .to_string(),
+            "Precision|0.12|  abc  "
+        );
+        assert_eq!(
+            TestEnumWithFormatSpecs::Binary { value: 5 }.to_string(),
+            "TestEnumWithFormatSpecs---|0b0101"
+        );
+    }
+
+    #[test]
+    fn test_unnamed_field_format_specs() {
+        assert_eq!(
+            TestEnumWithFormatSpecs::Positional(255, 1.25).to_string(),
+            "  255|+1.2|0xff"
+        );
+    }
+
+    #[test]
+    fn test_field_named_variant() {
+        assert_eq!(
+            TestEnumWithVariantField::Named {
+                variant: "field".to_string(),
+            }
+            .to_string(),
+            "Named: field"
+        );
+        assert_eq!(
+            TestEnumWithVariantField::Padded {
+                variant: "field".to_string(),
+            }
+            .to_string(),
+            " Padded field"
+        );
+    }
+
+    #[test]
+    fn test_field_named_f() {
+        assert_eq!(
+            TestEnumWithFormatterField::Named { f: 1 }.to_string(),
+            "<Named 1>"
+        );
+        assert_eq!(
+            TestEnumWithFormatterField::Quoted { f: 2 }.to_string(),
+            "\"<2>\""
+        );
+        assert_eq!(
+            TestEnumWithFormatterField::Transparent { f: 3 }.to_string(),
+            "<3>"
+        );
+        assert_eq!(
+            TestEnumWithFormatterField::With { f: 4, g: 5 }.to_string(),
+            "<4~5>"
+        );
+        assert_eq!(
+            TestEnumWithFormatterField::Plain { f: 6 }.to_string(),
+            "<Plain>"
+        );
+        assert_eq!(
+            format!("{:#}", TestEnumWithFormatterField::Plain { f: 6 }),
+            "<Plain { f: 6 }>"
+        );
+    }
+
+    #[test]
+    fn test_serde_rename() {
+        assert_eq!(TestEnumWithSerdeRename::FirstChoice.to_string(), "first");
+        assert_eq!(TestEnumWithSerdeRename::SecondChoice.to_string(), "second");
+        assert_eq!(
+            TestEnumWithSerdeRename::ThirdChoice.to_string(),
+            "third-choice!"
+        );
+        assert_eq!(TestEnumWithSerdeRename::Braces.to_string(), "{braces}");
+        assert_eq!(
+            TestEnumWithSerdeRename::NotRenamed.to_string(),
+            "not-renamed"
+        );
+        assert_eq!(TestEnumWithSerdeRename::FirstChoice.variant_name(), "first");
+    }
+
+    #[test]
+    fn test_serde_rename_is_opt_in() {
+        assert_eq!(
+            TestEnumWithoutSerdeRename::FirstChoice.to_string(),
+            "FirstChoice"
+        );
+    }
+
+    #[test]
+    fn test_display_names() {
+        assert_eq!(
+            TestEnumFromStr::display_names(),
+            ["light-red", "dark-green", "deep-blue!"]
+        );
+        assert_eq!(
+            TestEnum::display_names(),
+            ["Name", "Address", "DateOfBirth"]
+        );
+    }
+
+    #[test]
+    fn test_variant_case() {
+        assert_eq!(
+            TestEnumWithFormatVariantCase::PlainName.to_string(),
+            "plain_name"
+        );
+        assert_eq!(
+            TestEnumWithFormatVariantCase::FormattedName.to_string(),
+            "Formatted Name!"
+        );
+        assert_eq!(
+            TestEnumWithFormatVariantCase::FormattedField(1).to_string(),
+            "Formatted Field (1)"
+        );
+        assert_eq!(
+            TestEnumWithFormatVariantCase::OwnCase.to_string(),
+            "own-case?"
+        );
+        assert_eq!(
+            TestEnumWithFormatVariantCase::FormattedField(1).variant_name(),
+            "formatted_field"
+        );
+    }
+
+    #[test]
+    fn test_groups() {
+        assert_eq!(TestEnumWithGroups::Ok.to_string(), "<Ok>");
+        assert_eq!(
+            TestEnumWithGroups::NotFound.to_string(),
+            "<an error occurred>"
+        );
+        assert_eq!(
+            TestEnumWithGroups::Timeout(30).to_string(),
+            "<an error occurred>"
+        );
+        assert_eq!(
+            TestEnumWithGroups::Denied {
+                reason: "nope".to_string(),
+            }
+            .to_string(),
+            "<an error occurred>"
+        );
+        assert_eq!(TestEnumWithGroups::Braces.to_string(), "<{}>");
+        assert_eq!(
+            TestEnumWithGroups::Timeout(30).variant_name(),
+            "<an error occurred>"
+        );
+    }
+
+    #[test]
+    fn test_numeric_placeholder_radix_specs() {
+        assert_eq!(
+            TestEnumWithNumericSpecs::Radix(10).to_string(),
+            "0xa A 0o12 1010"
+        );
+    }
+
+    #[test]
+    fn test_numeric_placeholder_sign_and_padding_specs() {
+        assert_eq!(
+            TestEnumWithNumericSpecs::Padded(5, 7).to_string(),
+            "0b00000101|+7|+0007"
+        );
+    }
+
+    #[test]
+    fn test_numeric_placeholder_with_spaces() {
+        assert_eq!(
+            TestEnumWithNumericSpecs::Spaces(1, 2, 3).to_string(),
+            "1|0002|   3"
+        );
+        assert_eq!(TestEnumWithNumericSpecs::Shout.to_string(), "Shout!");
+    }
+
+    #[test]
+    fn test_display_cow() {
+        use alloc::borrow::Cow;
+
+        assert!(matches!(
+            TestEnum::Name.display_cow(),
+            Cow::Borrowed("Name")
+        ));
+        assert!(matches!(
+            TestEnumWithFormat::Name.display_cow(),
+            Cow::Borrowed("Name!")
+        ));
+        let cow = TestEnumWithFormat::DateOfBirth(1, 2, 2000).display_cow();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow, "DateOfBirth 1/2/2000");
+    }
+
+    #[test]
+    fn test_last_field() {
+        assert_eq!(TestEnumWithLast::Range(1, 2, 3).to_string(), "1..3");
+        assert_eq!(TestEnumWithLast::Single(7).to_string(), "  7");
+        assert_eq!(TestEnumWithLast::Bound(1, 2).to_string(), "bound");
+    }
+
+    #[test]
+    fn test_transparent_forwards_flags() {
+        assert_eq!(
+            format!("{}", TestEnumWithTransparentFlags::Wrapper(TestAlternate)),
+            "plain"
+        );
+        assert_eq!(
+            format!("{:#}", TestEnumWithTransparentFlags::Wrapper(TestAlternate)),
+            "alternate"
+        );
+        assert_eq!(
+            format!("{:>6}|", TestEnumWithTransparentFlags::Text { inner: "ab" }),
+            "    ab|"
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                TestEnumWithTransparentAffixes::Wrapper(TestAlternate)
+            ),
+            "<alternate>"
+        );
+    }
+
+    #[test]
+    fn test_debug_matches_display() {
+        for value in [
+            TestEnumWithDebug::NotFound,
+            TestEnumWithDebug::Code(404),
+            TestEnumWithDebug::Named { field: 1 },
+        ] {
+            assert_eq!(format!("{:?}", value), format!("{}", value));
+        }
+        assert_eq!(format!("{:?}", TestEnumWithDebug::Code(404)), "code(404)");
+        assert_eq!(
+            format!("{:#?}", TestEnumWithDebug::Named { field: 1 }),
+            "named"
+        );
+    }
+
+    #[test]
+    fn test_generic_enum_gets_bounds() {
+        type Generic = TestGenericEnum<f64, &'static str, u8>;
+        assert_eq!(Generic::Value(1.5).to_string(), "1.5");
+        assert_eq!(
+            Generic::Named {
+                inner: Some("a"),
+                hex: 255,
+            }
+            .to_string(),
+            "Some(\"a\")/ff"
+        );
+        assert_eq!(Generic::Forward(2.0).to_string(), "2");
+        assert_eq!(Generic::Empty.to_string(), "Empty");
+        assert_eq!(Generic::Empty.variant_name(), "Empty");
+    }
+
+    #[test]
+    fn test_generic_struct_and_lifetimes() {
+        assert_eq!(
+            TestGenericStruct {
+                value: 3,
+                unit: "kg",
+            }
+            .to_string(),
+            "3 kg"
+        );
+        assert_eq!(
+            TestGenericEnumWithLifetime::<u8>::Borrowed("text").to_string(),
+            "text"
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(TestEnumWithQuote::Plain.to_string(), "\"Plain\"");
+        assert_eq!(
+            TestEnumWithQuote::Formatted("a\\b").to_string(),
+            r#""Formatted \"a\\b\"""#
+        );
+        assert_eq!(
+            TestEnumWithQuote::Inner("say \"hi\"").to_string(),
+            r#""say \"hi\"""#
+        );
+        assert_eq!(TestEnumWithQuote::Backslash.to_string(), r#""backslash\\""#);
+        assert_eq!(
+            TestEnumWithQuote::Backslash.variant_name(),
+            r#""backslash\\""#
+        );
+        assert_eq!(TestEnumWithQuote::Unquoted.to_string(), "Unquoted");
+    }
+
+    #[test]
+    fn test_enum_level_quote() {
+        assert_eq!(TestEnumWithEnumQuote::Plain.to_string(), "\"<Plain>\"");
+        assert_eq!(
+            TestEnumWithEnumQuote::Formatted("\"x\"").to_string(),
+            r#""<say \"x\">""#
+        );
+    }
+
+    #[test]
+    fn test_discriminants() {
+        assert_eq!(TestEnumWithDiscriminants::First.to_string(), "000");
+        assert_eq!(TestEnumWithDiscriminants::Second.to_string(), "Second=5");
+        assert_eq!(TestEnumWithDiscriminants::Third.to_string(), "Third=6");
+        assert_eq!(TestEnumWithDiscriminants::Fourth.to_string(), "Fourth=0x1e");
+        assert_eq!(TestEnumWithDiscriminants::Fifth.to_string(), "Fifth=31");
+        assert_eq!(
+            TestEnumWithDiscriminants::Negative.to_string(),
+            "Negative=-2"
+        );
+        assert_eq!(
+            TestEnumWithDiscriminants::AfterNegative.to_string(),
+            "AfterNegative=-1"
+        );
+    }
+
+    #[test]
+    fn test_discriminant_field_wins() {
+        assert_eq!(
+            TestEnumWithDiscriminantField::Tagged {
+                discriminant: "tag"
+            }
+            .to_string(),
+            "tag"
+        );
+    }
+
+    #[test]
+    fn test_display_with_overrides() {
+        let overrides = |name: &str| (name == "DateOfBirth").then(|| "born".to_string());
+        assert_eq!(
+            TestEnumWithFormat::DateOfBirth(1, 2, 3).display_with(&overrides),
+            "born"
+        );
+        assert_eq!(TestEnumWithFormat::Name.display_with(&overrides), "Name!");
+        assert_eq!(
+            TestEnum::DateOfBirth(1, 2, 3).display_with(&|_| None),
+            "DateOfBirth"
+        );
+    }
+
+    #[test]
+    fn test_join_tuple_fields() {
+        assert_eq!(
+            TestEnumWithJoin::Triple("a", 2, 'c').to_string(),
+            "{a | 2 | c}"
+        );
+        assert_eq!(TestEnumWithJoin::Braces(1, 2).to_string(), "{1}{2}");
+        assert_eq!(TestEnumWithJoin::Empty().to_string(), "{}");
+    }
+
+    #[test]
+    fn test_no_coverage() {
+        assert_eq!(TestEnumWithoutCoverage::Plain.to_string(), "Plain");
+        assert_eq!(
+            format!("{:?}", TestEnumWithoutCoverage::Formatted(1)),
+            "Formatted 1"
+        );
+    }
+
+    #[test]
+    fn test_with_function() {
+        assert_eq!(
+            TestEnumWithFunctions::Area {
+                width: 2,
+                height: 3
+            }
+            .to_string(),
+            "<2x3 (6)>"
+        );
+        assert_eq!(TestEnumWithFunctions::Pair(1, 2).to_string(), "<1~2>");
+        assert_eq!(TestEnumWithFunctions::Nothing.to_string(), "<nothing>");
+        assert_eq!(TestEnumWithFunctions::Plain.to_string(), "<Plain>");
+    }
+
+    #[test]
+    fn test_empty_enum() {
+        assert!(TestEmptyEnum::display_names().is_empty());
+        assert!("anything".parse::<TestEmptyEnum>().is_err());
+    }
+
+    #[test]
+    fn test_rename_all_fields() {
+        let user = TestEnumWithRenamedFields::User {
+            firstName: "Ada",
+            lastName: "Lovelace",
+            userId: 7,
+        };
+        assert_eq!(user.to_string(), "Ada Lovelace, 0007");
+        assert_eq!(
+            TestEnumWithRenamedFields::Plain { plain: 1 }.to_string(),
+            "1"
+        );
+    }
+
+    #[test]
+    fn test_cfg_gated_variants() {
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(TestEnumWithCfg::WithStd.to_string(), "with std");
+            assert_eq!(TestEnumWithCfg::Either.to_string(), "std either");
+            assert_eq!("with std".parse(), Ok(TestEnumWithCfg::WithStd));
+            assert!("without std".parse::<TestEnumWithCfg>().is_err());
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            assert_eq!(TestEnumWithCfg::WithoutStd.to_string(), "without std");
+            assert_eq!(TestEnumWithCfg::Either.to_string(), "no_std either");
+            assert!("with std".parse::<TestEnumWithCfg>().is_err());
+        }
+        assert_eq!(TestEnumWithCfg::Always.to_string(), "always");
+        assert_eq!(TestEnumWithCfg::display_names().len(), 3);
+    }
+
+    #[test]
+    fn test_unrelated_attributes() {
+        assert_eq!(
+            TestEnumWithOtherAttributes::<u8>::First.to_string(),
+            "first"
+        );
+        assert_eq!(
+            TestEnumWithOtherAttributes::Second(2).to_string(),
+            "second: 2"
+        );
+    }
+
+    #[test]
+    fn test_qualified_names() {
+        assert_eq!(
+            TestEnumQualified::LightRed.to_string(),
+            "TestEnumQualified::light_red"
+        );
+        assert_eq!(
+            TestEnumQualified::Custom(3).to_string(),
+            "TestEnumQualified::custom(3)"
+        );
+        assert_eq!(
+            TestEnumQualified::Unqualified.to_string(),
+            "TestEnumQualified only"
+        );
+        assert_eq!(
+            TestEnumQualified::LightRed.variant_name(),
+            "TestEnumQualified::light_red"
+        );
+    }
+
+    #[test]
+    fn test_debug_specs() {
+        assert_eq!(
+            TestEnumWithDebugSpecs::Debug("a", Some(1)).to_string(),
+            "\"a\" and Some(1)"
+        );
+        assert_eq!(
+            TestEnumWithDebugSpecs::Pretty((1, 2)).to_string(),
+            "(\n    1,\n    2,\n)"
+        );
+        assert_eq!(TestEnumWithDebugSpecs::Spaced('x').to_string(), "'x'");
+        assert_eq!(
+            TestEnumWithDebugSpecs::Named { name: "n" }.to_string(),
+            "\"n\""
+        );
+    }
+
+    // The runtime transform has to agree with what the derive writes for the same case
+    #[test]
+    fn test_transform_ident_matches_derive() {
+        assert_eq!(
+            transform_ident("DateOfBirth", "Kebab").as_deref(),
+            Some(TestEnumWithAttribute::DateOfBirth(1, 2, 3).variant_name())
+        );
+        assert_eq!(
+            transform_ident("LightRed", "ScreamingSnake").as_deref(),
+            Some("LIGHT_RED")
+        );
+        assert_eq!(
+            transform_ident("lightRed", "FirstUpper").as_deref(),
+            Some("LightRed")
+        );
+        assert_eq!(
+            transform_ident("LightRed", "FirstLower").as_deref(),
+            Some("lightRed")
+        );
+        assert_eq!(transform_ident("", "FirstUpper").as_deref(), Some(""));
+        assert_eq!(
+            transform_ident("r#Match", "Upper").as_deref(),
+            Some("MATCH")
+        );
+        assert_eq!(transform_ident("LightRed", "kebab"), None);
+    }
+
+    // Every case name against a derive using it
+    #[test]
+    fn test_transform_ident_matches_derive_for_every_case() {
+        macro_rules! assert_matches_derive {
+            ($($case:literal),* $(,)?) => {$({
+                #[derive(EnumDisplay)]
+                #[enum_display(case = $case)]
+                enum Cased {
+                    HTTPServer2Ready,
+                }
+                assert_eq!(
+                    transform_ident("HTTPServer2Ready", $case).as_deref(),
+                    Some(Cased::HTTPServer2Ready.variant_name()),
+                    "case {}",
+                    $case
+                );
+            })*};
+        }
+        assert_matches_derive!(
+            "Upper",
+            "Lower",
+            "Title",
+            "Toggle",
+            "Camel",
+            "Pascal",
+            "UpperCamel",
+            "Snake",
+            "UpperSnake",
+            "ScreamingSnake",
+            "Kebab",
+            "Cobol",
+            "UpperKebab",
+            "Train",
+            "Flat",
+            "UpperFlat",
+            "Alternating",
+            "FirstUpper",
+            "FirstLower",
+        );
+    }
+
+    #[test]
+    fn test_letter_case() {
+        assert_eq!(TestEnumWithLetterCase::LightRed.to_string(), "light-red");
+        assert_eq!(TestEnumWithLetterCase::DarkGreen.to_string(), "DARK-GREEN");
+        assert_eq!(
+            TestEnumWithLetterCase::DarkGreen.variant_name(),
+            "DARK-GREEN"
+        );
+        assert_eq!(
+            TestEnumWithLetterCase::Named("straße").to_string(),
+            "NAMED STRASSE"
+        );
+        assert_eq!(
+            TestEnumWithLetterCase::Quoted("Say \"Hi\"").to_string(),
+            r#""say \"hi\"""#
+        );
+        assert_eq!(TestEnumWithLetterCase::Forwarded("x").to_string(), "X");
+    }
+
+    #[test]
+    fn test_trailing_commas() {
+        assert_eq!(
+            TestEnumWithTrailingCommas::LightRed.to_string(),
+            "<light_red>"
+        );
+        assert_eq!(
+            TestEnumWithTrailingCommas::DarkGreen(1).to_string(),
+            "<dark_green 1>"
+        );
+    }
+
+    #[test]
+    fn test_variant_index() {
+        let address = TestEnum::Address {
+            street: "1 Main St".to_string(),
+            city: "Springfield".to_string(),
+            state: "IL".to_string(),
+            zip: "62701".to_string(),
+        };
+        assert_eq!(TestEnum::Name.variant_index(), 0);
+        assert_eq!(address.variant_index(), 1);
+        assert_eq!(TestEnum::DateOfBirth(1, 2, 3).variant_index(), 2);
+        assert_eq!(TestEnumWithDiscriminants::Second.variant_index(), 1);
+        assert_eq!(TestEnumWithDiscriminants::AfterNegative.variant_index(), 6);
+    }
+
+    #[test]
+    fn test_positional_placeholders_in_named_variants() {
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Pair {
+                first: 7,
+                second: "b"
+            }
+            .to_string(),
+            "Pair: b,    7"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Mixed { first: 'x' }.to_string(),
+            "'x' is x"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Address {
+                street: 6,
+                city: "Bergen"
+            }
+            .to_string(),
+            "6 6, Bergen Bergen"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Address {
+                street: 8,
+                city: "Bergen"
+            }
+            .to_string(),
+            "8 8, Bergen   Bergen"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Shadowed {
+                street: "Main St",
+                city: "Oslo"
+            }
+            .to_string(),
+            "4 letters in Oslo"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Variant { variant: "x" }.to_string(),
+            "Variant-x"
+        );
+        assert_eq!(
+            TestEnumWithPositionalNamedFields::Enum { r#enum: 3 }.to_string(),
+            "3 of TestEnumWithPositionalNamedFields"
+        );
+    }
+
+    #[test]
+    fn test_strip_affixes() {
+        assert_eq!(
+            TestEnumWithStrippedNames::HttpNotFoundError.to_string(),
+            "not-found"
+        );
+        assert_eq!(
+            TestEnumWithStrippedNames::HttpTimeout.to_string(),
+            "timeout"
+        );
+        assert_eq!(TestEnumWithStrippedNames::ParseError.to_string(), "parse");
+        assert_eq!(
+            TestEnumWithStrippedNames::Unrelated.to_string(),
+            "unrelated"
+        );
+        assert_eq!(TestEnumWithStrippedNames::Error.to_string(), "error");
+        assert_eq!(
+            TestEnumWithStrippedNames::HttpStatusError(500).to_string(),
+            "status (500)"
+        );
+    }
+
+    #[test]
+    fn test_from_str_or_default() {
+        assert_eq!(
+            TestEnumWithDefault::from_str_or_default("error"),
+            TestEnumWithDefault::Error
+        );
+        assert_eq!(
+            TestEnumWithDefault::from_str_or_default("log_level"),
+            TestEnumWithDefault::LogLevel
+        );
+        assert_eq!(
+            TestEnumWithDefault::from_str_or_default("warn"),
+            TestEnumWithDefault::Warning
+        );
+        assert_eq!(
+            TestEnumWithDefault::from_str_or_default("loud"),
+            TestEnumWithDefault::Warning
+        );
+    }
+
+    #[test]
+    fn test_non_exhaustive() {
+        assert_eq!(TestNonExhaustiveEnum::Known.to_string(), "known");
+        assert_eq!("later".parse(), Ok(TestNonExhaustiveEnum::Later));
+        assert_eq!(
+            TestNonExhaustiveFields::Record { id: 3 }.to_string(),
+            "Record 3"
+        );
+    }
+
+    #[test]
+    fn test_debug_fields() {
+        assert_eq!(
+            TestEnumWithDebugFields::Tuple(1, "a", vec![2]).to_string(),
+            "Tuple(1, \"a\", [2])"
+        );
+        assert_eq!(
+            TestEnumWithDebugFields::<()>::Named { id: 3, r#type: 'x' }.to_string(),
+            "Named { id: 3, type: 'x' }"
+        );
+        assert_eq!(TestEnumWithDebugFields::<()>::Unit.to_string(), "Unit");
+        assert_eq!(TestEnumWithDebugFields::<()>::Plain(4).to_string(), "Plain");
+    }
+
+    #[test]
+    fn test_enum_level_debug_fields() {
+        assert_eq!(
+            TestEnumWithDefaultDebugFields::EmptyName.to_string(),
+            "empty_name"
+        );
+        assert_eq!(
+            TestEnumWithDefaultDebugFields::Single(None).to_string(),
+            "single(None)"
+        );
+        assert_eq!(
+            TestEnumWithDefaultDebugFields::Custom(2).to_string(),
+            "custom #2"
+        );
+    }
+
+    #[test]
+    fn test_fields_are_borrowed() {
+        let named = TestEnumWithBorrowedFields::Named {
+            names: vec!["a".to_string(), "b".to_string()],
+            owner: NotClone("me".to_string()),
+        };
+        assert_eq!(named.to_string(), "Named: [\"a\", \"b\"] me");
+        // Formatting again proves the first call didn't consume anything
+        assert_eq!(named.to_string(), "Named: [\"a\", \"b\"] me");
+
+        let unnamed =
+            TestEnumWithBorrowedFields::Unnamed(NotClone("x".to_string()), vec!["y".to_string()]);
+        assert_eq!(unnamed.to_string(), "x and [\"y\"]");
+        assert_eq!(
+            TestEnumWithBorrowedFields::Joined(
+                NotClone("l".to_string()),
+                NotClone("r".to_string())
+            )
+            .to_string(),
+            "l/r"
+        );
+        assert_eq!(
+            TestEnumWithBorrowedFields::Transparent(NotClone("inner".to_string())).to_string(),
+            "inner"
+        );
+        assert_eq!(
+            TestEnumWithBorrowedFields::Debugged(NotClone("d".to_string())).to_string(),
+            "Debugged(NotClone(\"d\"))"
+        );
+        assert_eq!(
+            TestEnumWithBorrowedFields::With(NotClone("w".to_string())).to_string(),
+            "with w"
+        );
+
+        if let TestEnumWithBorrowedFields::Named { names, owner } = named {
+            assert_eq!(names.len(), 2);
+            assert_eq!(owner.0, "me");
+        }
+    }
+
+    #[test]
+    fn test_ascii_only() {
+        assert_eq!(TestEnumAsciiOnly::LogIn.to_string(), "<log-in>");
+        // Only the literals are checked, runtime fields can still write anything
+        assert_eq!(
+            TestEnumAsciiOnly::LogInAs("zoë").to_string(),
+            "<log-in-as as zoë>"
+        );
+        assert_eq!(TestEnumAsciiOnly::Joined(1, 2).to_string(), "<1; 2>");
+    }
+
+    #[test]
+    fn test_as_cstr() {
+        const FIRST_NAME: &core::ffi::CStr = TestEnumCStr::FirstName.as_cstr();
+        assert_eq!(FIRST_NAME.to_bytes_with_nul(), b"<first_name>\0");
+        for value in [
+            TestEnumCStr::FirstName,
+            TestEnumCStr::Loud,
+            TestEnumCStr::Quoted,
+        ] {
+            assert_eq!(value.as_cstr().to_str(), Ok(value.to_string().as_str()));
+        }
+        // Output written at runtime falls back to the transformed name, like `variant_name`
+        assert_eq!(
+            TestEnumCStr::WithFunction.as_cstr().to_str(),
+            Ok("with_function")
+        );
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(
+            TestEnumWithDedent::Block(1).to_string(),
+            "Block:\n    1\nend"
+        );
+        // The first line doesn't have to share the indentation of the rest
+        assert_eq!(TestEnumWithDedent::FirstLine.to_string(), "first\nsecond");
+        assert_eq!(TestEnumWithDedent::Kept.to_string(), "\n            Kept");
+    }
 
-pub use enum_display_macro::*;
+    #[test]
+    fn test_enum_level_dedent() {
+        assert_eq!(TestEnumWithDefaultDedent::Plain.to_string(), "<Plain>\n");
+        assert_eq!(
+            TestEnumWithDefaultDedent::Nested(2).to_string(),
+            "Nested\n  2"
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_write_display() {
+        let value = TestEnumWithDebugFields::<()>::Named { id: 3, r#type: 'x' };
+        let write = WriteDisplay(&value, TestEnumWithDebugFields::write_display);
+        assert_eq!(format!("{}", write), value.to_string());
+        assert_eq!(
+            format!("{}", WriteDisplay(&TestEnum::Name, TestEnum::write_display)),
+            "Name"
+        );
+        assert_eq!(
+            format!(
+                "{:>8}",
+                WriteDisplay(&TestEnumWithPad::Name, TestEnumWithPad::write_display)
+            ),
+            format!("{:>8}", TestEnumWithPad::Name)
+        );
+    }
 
-    #[allow(dead_code)]
-    #[derive(EnumDisplay)]
-    enum TestEnum {
-        Name,
-        Address {
-            street: String,
-            city: String,
-            state: String,
-            zip: String,
-        },
-        DateOfBirth(u32, u32, u32),
+    #[test]
+    fn test_doc_comments_are_ignored() {
+        assert_eq!(TestEnumWithDocs::PlainName.to_string(), "plain-name");
+        assert_eq!(TestEnumWithDocs::WithField(7).to_string(), "with-field 7");
+        assert_eq!(
+            TestEnumWithDocs::Documented { id: 1 }.to_string(),
+            "documented"
+        );
     }
 
-    #[allow(dead_code)]
-    #[derive(EnumDisplay)]
-    #[enum_display(case = "Kebab")]
-    enum TestEnumWithAttribute {
-        Name,
-        Address {
-            street: String,
-            city: String,
-            state: String,
-            zip: String,
-        },
-        DateOfBirth(u32, u32, u32),
+    #[test]
+    fn test_extern_constants() {
+        assert_eq!(TestEnumWithExterns::Release.to_string(), "Release v1.2");
+        assert_eq!(
+            TestEnumWithExterns::Build("nightly").to_string(),
+            "nightly v1.2+007"
+        );
+        assert_eq!(
+            TestEnumWithExterns::Limited { current: 1 }.to_string(),
+            "Limited up to 10"
+        );
     }
 
     #[test]
-    fn test_unit_field_variant() {
-        assert_eq!(TestEnum::Name.to_string(), "Name");
+    fn test_word_boundaries() {
+        assert_eq!(
+            TestEnumWithDefaultBoundaries::XMLHttpRequest.to_string(),
+            "xml_http_request"
+        );
+        assert_eq!(
+            TestEnumWithDefaultBoundaries::HTTPSConnection.to_string(),
+            "https_connection"
+        );
+        assert_eq!(
+            TestEnumWithDefaultBoundaries::Http2Server.to_string(),
+            "http_2_server"
+        );
+
+        // Without the acronym boundary a run of capitals stays one word
+        assert_eq!(
+            TestEnumWithBoundaries::XMLHttpRequest.to_string(),
+            "xmlhttp_request"
+        );
+        assert_eq!(
+            TestEnumWithBoundaries::HTTPSConnection.to_string(),
+            "httpsconnection"
+        );
+        assert_eq!(
+            TestEnumWithBoundaries::Http2Server.to_string(),
+            "http2_server"
+        );
+        assert_eq!(TestEnumWithBoundaries::Formatted.to_string(), "formatted");
+
+        assert_eq!(TestEnumWithoutBoundaries::LongName.to_string(), "longname");
+        // Field names are split with the same boundaries
+        assert_eq!(
+            TestEnumWithoutBoundaries::Sized { maxSize: 4 }.to_string(),
+            "sized 4"
+        );
     }
 
     #[test]
-    fn test_named_fields_variant() {
+    fn test_parse_case_insensitive() {
+        for input in ["RED", "red", "ReD"] {
+            assert_eq!(input.parse(), Ok(TestEnumCaseInsensitive::Red));
+            assert_eq!(
+                TestEnumCaseInsensitive::try_from(input),
+                Ok(TestEnumCaseInsensitive::Red)
+            );
+        }
+        assert_eq!("light blue".parse(), Ok(TestEnumCaseInsensitive::LightBlue));
+        assert_eq!("ÉCLAIR".parse(), Ok(TestEnumCaseInsensitive::Eclair));
+        assert!("light-blue".parse::<TestEnumCaseInsensitive>().is_err());
+        // Only parsing ignores case, the output keeps it
+        assert_eq!(TestEnumCaseInsensitive::LightBlue.to_string(), "Light Blue");
+    }
+
+    #[test]
+    fn test_unique() {
+        assert_eq!(TestEnumUnique::Kilobyte.to_string(), "kilobyte");
+        assert_eq!(TestEnumUnique::Megabyte.to_string(), "megabyte");
+        assert_eq!(TestEnumUnique::Kilo("byte").to_string(), "kilobyte");
+    }
+
+    #[test]
+    fn test_variant_enum_display_attribute() {
         assert_eq!(
-            TestEnum::Address {
-                street: "123 Main St".to_string(),
-                city: "Any Town".to_string(),
-                state: "CA".to_string(),
-                zip: "12345".to_string()
+            TestEnumWithVariantEnumDisplay::Renamed.to_string(),
+            "Custom"
+        );
+        assert_eq!(
+            TestEnumWithVariantEnumDisplay::Formatted(1).to_string(),
+            "FORMATTED 1"
+        );
+        assert_eq!(
+            TestEnumWithVariantEnumDisplay::KebabCase.to_string(),
+            "kebab-case"
+        );
+        assert_eq!(
+            TestEnumWithVariantEnumDisplay::Mixed.to_string(),
+            "\"MIXED\""
+        );
+        assert_eq!(
+            TestStructWithEnumDisplay(2).to_string(),
+            "TEST STRUCT WITH ENUM DISPLAY: 2"
+        );
+    }
+
+    #[test]
+    fn test_arity() {
+        assert_eq!(TestEnumWithArity::Unit.to_string(), "Unit/0");
+        assert_eq!(TestEnumWithArity::Unit.variant_name(), "Unit/0");
+        assert_eq!(
+            TestEnumWithArity::Address {
+                street: "1 Main St".to_string(),
+                city: "Springfield".to_string(),
+                state: "IL".to_string(),
+                zip: "62701".to_string(),
             }
             .to_string(),
-            "Address"
+            "Address/4"
+        );
+        assert_eq!(
+            TestEnumWithArity::DateOfBirth(1, 2, 2000).to_string(),
+            "DateOfBirth/3"
+        );
+        assert_eq!(TestEnumWithArity::Padded(0).to_string(), "Padded   1");
+        assert_eq!(
+            TestEnumWithArity::Shadowed { arity: "many" }.to_string(),
+            "Shadowed many"
         );
     }
 
     #[test]
-    fn test_unnamed_fields_variant() {
-        assert_eq!(TestEnum::DateOfBirth(1, 1, 2000).to_string(), "DateOfBirth");
+    fn test_debug_alternate() {
+        let address = TestEnumWithDebugAlternate::Address {
+            street: "Main St",
+            r#type: 'h',
+        };
+        assert_eq!(format!("{}", address), "[address Main St]");
+        assert_eq!(
+            format!("{:#}", address),
+            "[address { street: \"Main St\", type: 'h' }]"
+        );
+        let pair = TestEnumWithDebugAlternate::Pair(1, None);
+        assert_eq!(format!("{}", pair), "[pair]");
+        assert_eq!(format!("{:#}", pair), "[pair(1, None)]");
+        assert_eq!(
+            format!("{:#}", TestEnumWithDebugAlternate::EmptyName),
+            "[empty_name]"
+        );
+        assert_eq!(
+            format!("{:#}", TestEnumWithDebugAlternate::Inner("x")),
+            "[inner(\"x\")]"
+        );
+        let quoted = TestEnumWithQuotedDebugAlternate::Fields { x: 1 };
+        assert_eq!(format!("{}", quoted), "\"<Fields>\"");
+        assert_eq!(format!("{:#}", quoted), "\"<Fields { x: 1 }>\"");
+        assert_eq!(
+            format!("{:#9}", TestEnumWithQuotedDebugAlternate::Empty),
+            "\"<Empty>\""
+        );
+        assert_eq!(
+            format!("{:#10}", TestEnumWithQuotedDebugAlternate::Empty),
+            "\"<Empty>\" "
+        );
     }
 
     #[test]
-    fn test_unit_field_variant_case_transform() {
-        assert_eq!(TestEnumWithAttribute::Name.to_string(), "name");
+    fn test_debug_alternate_bounds_generic_fields() {
+        let a = TestEnumWithGenericDebugAlternate::A("x");
+        assert_eq!(format!("{}", a), "A");
+        assert_eq!(format!("{:#}", a), "A(\"x\")");
+        let b = TestEnumWithGenericDebugAlternate::B { x: 1 };
+        assert_eq!(format!("{:#}", b), "B { x: 1 }");
+        let c = TestEnumWithGenericDebugAlternate::<u8>::C;
+        assert_eq!(format!("{:#}", c), "C");
     }
 
     #[test]
-    fn test_named_fields_variant_case_transform() {
+    fn test_json() {
+        assert_eq!(TestEnumJson::RedApple.to_string(), r#""red_apple""#);
         assert_eq!(
-            TestEnumWithAttribute::Address {
-                street: "123 Main St".to_string(),
-                city: "Any Town".to_string(),
-                state: "CA".to_string(),
-                zip: "12345".to_string()
+            TestEnumJson::Escaped.to_string(),
+            r#""say \"hi\"\nthen\tleave\u0001""#
+        );
+        assert_eq!(
+            TestEnumJson::Formatted("a\\b\r\u{1f}").to_string(),
+            r#""formatted: a\\b\r\u001f""#
+        );
+        assert_eq!(
+            TestEnumJson::Inner("line\nbreak, naïve").to_string(),
+            r#""line\nbreak, naïve""#
+        );
+    }
+
+    #[test]
+    fn test_from_repr() {
+        assert_eq!(
+            TestEnumFromRepr::from_repr(100),
+            Some(TestEnumFromRepr::Continue)
+        );
+        assert_eq!(
+            TestEnumFromRepr::from_repr(101),
+            Some(TestEnumFromRepr::SwitchingProtocols)
+        );
+        assert_eq!(TestEnumFromRepr::from_repr(200), Some(TestEnumFromRepr::Ok));
+        assert_eq!(
+            TestEnumFromRepr::from_repr(404),
+            Some(TestEnumFromRepr::NotFound)
+        );
+        // Variants with fields and numbers without a variant aren't found
+        assert_eq!(TestEnumFromRepr::from_repr(300), None);
+        assert_eq!(TestEnumFromRepr::from_repr(u16::MAX), None);
+
+        // Discriminants given by constant expressions and negative ones are matched too
+        assert!(matches!(
+            TestEnumWithDiscriminants::from_repr(30),
+            Some(TestEnumWithDiscriminants::Fourth)
+        ));
+        assert!(matches!(
+            TestEnumWithDiscriminants::from_repr(-1),
+            Some(TestEnumWithDiscriminants::AfterNegative)
+        ));
+    }
+
+    #[test]
+    fn test_width_from_field() {
+        assert_eq!(
+            TestEnumWithWidthFields::Padded("ab", 4).to_string(),
+            "[ab  ]"
+        );
+        assert_eq!(
+            TestEnumWithWidthFields::Truncated("abcdef", 5, 3).to_string(),
+            "[  abc]"
+        );
+        assert_eq!(
+            TestEnumWithWidthFields::Named { value: 7, width: 3 }.to_string(),
+            "[007]"
+        );
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(TestEnumWithCast::Scaled(255).to_string(), "255000");
+        assert_eq!(TestEnumWithCast::Difference(1, 2).to_string(), "1-2=2");
+    }
+
+    #[test]
+    fn test_all_fields() {
+        assert_eq!(
+            TestEnumWithAllFields::Person {
+                name: "Ann",
+                age: 40,
+                r#type: 'x'
             }
             .to_string(),
-            "address"
+            "person { name: Ann, age: 40, type: x }"
+        );
+        assert_eq!(
+            TestEnumWithAllFields::Separated {
+                low: 0.5,
+                high: 1.0
+            }
+            .to_string(),
+            "separated { low: 0.5 | high: 1 }"
+        );
+        assert_eq!(
+            TestEnumWithAllFields::Braces { a: 1, b: 2 }.to_string(),
+            "braces { a: 1}{b: 2 }"
+        );
+        assert_eq!(TestEnumWithAllFields::Nothing {}.to_string(), "nothing {}");
+        assert_eq!(
+            TestEnumWithAllFields::Nothing {}.variant_name(),
+            "nothing {}"
         );
     }
 
     #[test]
-    fn test_unnamed_fields_variant_case_transform() {
+    fn test_generic_enum_with_where_clause() {
+        static VALUE: u8 = 5;
+        let value: TestGenericEnumWithWhere<u8, &str> = TestGenericEnumWithWhere::Static(&VALUE);
+        assert_eq!(value.to_string(), "5");
+        let labelled: TestGenericEnumWithWhere<u8, &str> = TestGenericEnumWithWhere::Labelled {
+            label: Label("tag"),
+        };
+        assert_eq!(labelled.to_string(), "[tag]");
+        let pair: TestGenericEnumWithWhere<u8, &str> = TestGenericEnumWithWhere::Pair(1, Some(2));
+        assert_eq!(pair.to_string(), "1=Some(2)");
+    }
+
+    #[test]
+    fn test_symbolic_output() {
+        assert_eq!(TestEnumWithSymbolicOutput::LightRed.to_string(), "Red");
         assert_eq!(
-            TestEnumWithAttribute::DateOfBirth(1, 1, 2000).to_string(),
-            "date-of-birth"
+            TestEnumWithSymbolicOutput::DarkBlue.to_string(),
+            "Palette::DarkBlue"
+        );
+        assert_eq!(TestEnumWithSymbolicOutput::Green.to_string(), "Green");
+        assert_eq!(
+            TestEnumWithSymbolicOutput::Unchanged.to_string(),
+            "unchanged"
+        );
+        assert_eq!(
+            "Palette::DarkBlue".parse::<TestEnumWithSymbolicOutput>(),
+            Ok(TestEnumWithSymbolicOutput::DarkBlue)
+        );
+    }
+
+    #[test]
+    fn test_self_method_binding() {
+        assert_eq!(TestEnumWithSelfBinding::Sum(2, 3).to_string(), "2+3=5");
+        assert_eq!(
+            TestEnumWithSelfBinding::Named { name: "x" }.to_string(),
+            "named x"
+        );
+        assert_eq!(TestEnumWithSelfBinding::Unit.to_string(), "unit");
+    }
+
+    // `display_into` has match arms of its own, which have to write what `fmt` does
+    #[test]
+    fn test_display_into_matches_display() {
+        macro_rules! assert_into_matches {
+            ($($value:expr),* $(,)?) => {$({
+                let value = $value;
+                let mut buf = String::new();
+                value.display_into(&mut buf).unwrap();
+                assert_eq!(buf, value.to_string());
+            })*};
+        }
+        assert_into_matches!(
+            TestEnumWithTrim::Padded("  a b  "),
+            TestEnumWithTrim::Named { name: "x" },
+            TestEnumWithTrim::Quoted("\"hi\""),
+            TestEnumWithTrim::Untrimmed,
+            TestEnumWithTruncate::Formatted("héllo wörld"),
+            TestEnumWithTruncate::Quoted("abcdef"),
+            TestEnumWithTruncate::Trimmed("  abcdef  "),
+            TestEnumWithLetterCase::DarkGreen,
+            TestEnumWithLetterCase::Named("straße"),
+            TestEnumWithLetterCase::Quoted("Say \"Hi\""),
+            TestEnumWithLetterCase::Forwarded("a"),
+            TestEnumWithFunctions::Area {
+                width: 2,
+                height: 3
+            },
+            TestEnumWithFunctions::Plain,
+            TestEnumWithFormatterField::Named { f: 1 },
+            TestEnumWithFormatterField::Quoted { f: 2 },
+            TestEnumWithBuffer::Named { buf: 3 },
+        );
+    }
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(TestEnumWithTrim::Padded("  a b  ").to_string(), "<  a b  >");
+        assert_eq!(
+            TestEnumWithTrim::Named { name: "x" }.to_string(),
+            "<  NAMED      x >"
+        );
+        assert_eq!(
+            TestEnumWithTrim::Quoted("\"hi\"").to_string(),
+            "\"< \\\"hi\\\" >\""
+        );
+        assert_eq!(TestEnumWithTrim::Fixed.to_string(), "<  FIXED  >");
+        assert_eq!(TestEnumWithTrim::Fixed.variant_name(), "<  FIXED  >");
+        assert_eq!(TestEnumWithTrim::Blank(" \t\n ").to_string(), "< \t\n >");
+        assert_eq!(TestEnumWithTrim::Untrimmed.to_string(), " <UNTRIMMED> ");
+    }
+
+    #[test]
+    fn test_trim_formats_once() {
+        let chunks = TestEnumWithTrimmedPieces::Chunks(Chunked(std::cell::Cell::new(0)));
+        assert_eq!(chunks.to_string(), "ab \tcd");
+        let TestEnumWithTrimmedPieces::Chunks(chunked) = &chunks else {
+            unreachable!()
+        };
+        assert_eq!(chunked.0.get(), 1);
+        // More runs of whitespace than are held back without allocating
+        assert_eq!(
+            TestEnumWithTrimmedPieces::Text(" a \t \t \t \t \t \t b \t \t \t \t \t \t ")
+                .to_string(),
+            "a \t \t \t \t \t \t b"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(
+            TestEnumWithTruncate::Formatted("héllo wörld").to_string(),
+            "héllo w…"
+        );
+        assert_eq!(
+            TestEnumWithTruncate::Formatted("🦀🦀🦀").to_string(),
+            "🦀🦀🦀"
+        );
+        assert_eq!(
+            TestEnumWithTruncate::Formatted("🦀🦀🦀🦀🦀🦀🦀🦀").to_string(),
+            "🦀🦀🦀🦀🦀🦀🦀…"
+        );
+        assert_eq!(TestEnumWithTruncate::VeryLongName.to_string(), "VeryL…");
+        assert_eq!(TestEnumWithTruncate::VeryLongName.variant_name(), "VeryL…");
+        assert_eq!(TestEnumWithTruncate::Fixed.to_string(), "日本語…");
+        assert_eq!(TestEnumWithTruncate::Fixed.variant_name(), "日本語…");
+        assert_eq!(
+            TestEnumWithTruncate::Quoted("a\"bcdef").to_string(),
+            "\"A\\\"BC…\""
+        );
+        assert_eq!(TestEnumWithTruncate::Transparent(12345).to_string(), "123…");
+        assert_eq!(TestEnumWithTruncate::Trimmed("  ab  ").to_string(), "ab");
+        assert_eq!(
+            TestEnumWithTruncate::Trimmed(" abcde ").to_string(),
+            "abcd…"
+        );
+        assert_eq!(TestEnumWithTruncate::Exact.to_string(), "Exact");
+    }
+
+    #[test]
+    fn test_field_repr() {
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Tuple(DebugOnly(1)).to_string(),
+            "Tuple: DebugOnly(1)"
+        );
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Named {
+                inner: DebugOnly(2),
+                name: "ab"
+            }
+            .to_string(),
+            "Named: DebugOnly(2)   ab \"ab\""
+        );
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Aliased("x", DebugOnly(3)).to_string(),
+            "\"x\", DebugOnly(3)"
+        );
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Bound { text: "abc" }.to_string(),
+            "\"abc\" (3)"
+        );
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Joined(DebugOnly(4), 'c').to_string(),
+            "DebugOnly(4) 'c'"
+        );
+        assert_eq!(
+            TestEnumWithDebugFieldRepr::Displayed("plain").to_string(),
+            "plain {0}"
+        );
+        assert_eq!(TestEnumWithDebugFieldRepr::Unit.to_string(), "Unit");
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(
+            TestEnumFromStr::iter().collect::<Vec<_>>(),
+            [
+                TestEnumFromStr::LightRed,
+                TestEnumFromStr::DarkGreen,
+                TestEnumFromStr::DeepBlue
+            ]
+        );
+        let displayed: Vec<String> = TestEnumFromStr::iter().map(|v| v.to_string()).collect();
+        assert_eq!(displayed, ["light-red", "dark-green", "deep-blue!"]);
+        assert_eq!(TestEmptyEnum::iter().count(), 0);
+    }
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(
+            TestEnumWithGroupedDigits::Count(1_000_000).to_string(),
+            "1,000,000 items"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Count(999).to_string(),
+            "999 items"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Count(1000).to_string(),
+            "1,000 items"
+        );
+        assert_eq!(TestEnumWithGroupedDigits::Count(0).to_string(), "0 items");
+        assert_eq!(
+            TestEnumWithGroupedDigits::Count(u64::MAX).to_string(),
+            "18,446,744,073,709,551,615 items"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Change(-1_234_567).to_string(),
+            "[-1,234,567]"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Change(-12).to_string(),
+            "[       -12]"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Change(12345).to_string(),
+            "[    12,345]"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Named {
+                total: i128::MIN,
+                count: 10_000
+            }
+            .to_string(),
+            "-170,141,183,460,469,231,731,687,303,715,884,105,728 over 10000, \
+             -170,141,183,460,469,231,731,687,303,715,884,105,728"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Aliased(65536).to_string(),
+            "65,536 B, 65,536"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Bound { x: 1500 }.to_string(),
+            "1,500 -> 3000"
+        );
+        assert_eq!(
+            TestEnumWithGroupedDigits::Cast(255, 65535).to_string(),
+            "255/65,535"
+        );
+    }
+
+    #[test]
+    fn test_precision_truncates_with_pad() {
+        assert_eq!(format!("{:.3}", TestEnumWithPad::Name), "Nam");
+        assert_eq!(format!("{:>6.3}|", TestEnumWithPad::Name), "   Nam|");
+        assert_eq!(format!("{:.10}", TestEnumWithPad::Name), "Name");
+        assert_eq!(
+            format!("{:.4}", TestEnumWithPad::DateOfBirth(1, 1, 2000)),
+            "Date"
+        );
+        assert_eq!(
+            format!("{:.3}", TestEnumWithPadAndFormat::Unformatted),
+            "Unf"
+        );
+        assert_eq!(format!("{:.3}", TestEnumWithPadAndFormat::Name), "Name!");
+        // Without `pad` the precision is ignored like the width
+        assert_eq!(format!("{:.3}", TestEnum::Name), "Name");
+        assert_eq!(
+            format!("{:.3}", TestEnumWithFormat::Unformatted),
+            "Unformatted"
         );
     }
 }
@@ -0,0 +1,84 @@
+// Only checked by `cargo clippy --all-targets`, downstream crates may deny clippy's lints wholesale
+#![deny(clippy::all, clippy::pedantic)]
+
+use enum_display::{EnumDisplay, EnumFromStr, EnumTryFrom};
+
+#[derive(Debug, PartialEq, EnumDisplay, EnumFromStr, EnumTryFrom)]
+enum Single {
+    Only,
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum SingleFormatted {
+    #[display("{variant}: {0}")]
+    Only(u32),
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(case = "Kebab", debug, debug_alternate)]
+enum Mixed {
+    Plain,
+    #[display("{variant}")]
+    JustName,
+    #[display("{x},{y}")]
+    Point {
+        x: i32,
+        y: i32,
+    },
+    #[display("{0:1$}")]
+    Padded(&'static str, usize),
+    #[display(transparent)]
+    Inner(String),
+    #[display(join = ", ")]
+    Joined(u8, u8),
+    #[display(debug_fields)]
+    Fields {
+        name: &'static str,
+    },
+    #[display(cast = "u64", "{0}")]
+    Cast(u8),
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(quote, pad, case = "Snake")]
+enum Quoted {
+    FirstValue,
+    SecondValue,
+}
+
+#[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+#[enum_display(parse_case_insensitive)]
+#[repr(u8)]
+enum Repr {
+    A = 1,
+    #[display(default)]
+    B = 2,
+}
+
+#[derive(EnumDisplay)]
+enum Empty {}
+
+#[derive(EnumDisplay)]
+struct Unit;
+
+#[derive(EnumDisplay)]
+#[display("{0}")]
+struct Wrapper(u32);
+
+#[test]
+fn test_clippy_clean_output() {
+    assert_eq!(Single::Only.to_string(), "Only");
+    assert_eq!("Only".parse::<Single>(), Ok(Single::Only));
+    assert_eq!(SingleFormatted::Only(1).to_string(), "Only: 1");
+    assert_eq!(Mixed::Point { x: 1, y: 2 }.to_string(), "1,2");
+    assert_eq!(format!("{:#}", Mixed::Cast(3)), "cast(3)");
+    assert_eq!(Quoted::SecondValue.to_string(), "\"second_value\"");
+    assert_eq!(Repr::from_repr(2), Some(Repr::B));
+    assert_eq!(Repr::from_str_or_default("x"), Repr::B);
+    assert_eq!(Empty::display_names().len(), 0);
+    assert_eq!(Unit.to_string(), "Unit");
+    assert_eq!(Wrapper(4).to_string(), "4");
+}
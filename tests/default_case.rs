@@ -0,0 +1,8 @@
+// The default case is read by the derive when it expands, so it has to be set for the build of
+// the test crates rather than for this test
+#[test]
+fn default_case() {
+    std::env::set_var("ENUM_DISPLAY_DEFAULT_CASE", "Kebab");
+    let t = trybuild::TestCases::new();
+    t.pass("tests/default_case/*.rs");
+}
@@ -0,0 +1,20 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Defaulted {
+    LightRed,
+    #[display("{variant} {0}")]
+    DarkGreen(u8),
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(case = "Snake")]
+enum Explicit {
+    LightRed,
+}
+
+fn main() {
+    assert_eq!(Defaulted::LightRed.to_string(), "light-red");
+    assert_eq!(Defaulted::DarkGreen(1).to_string(), "dark-green 1");
+    assert_eq!(Explicit::LightRed.to_string(), "light_red");
+}
@@ -0,0 +1,41 @@
+// Deriving on an enum with deprecated variants shouldn't warn about the generated code using
+// them, only the user's own uses of the variants should
+#![deny(deprecated)]
+
+use enum_display::{EnumDisplay, EnumFromStr, EnumTryFrom};
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumDisplay, EnumFromStr, EnumTryFrom)]
+#[enum_display(case = "Kebab")]
+enum Unit {
+    Current,
+    #[deprecated(note = "use `Current` instead")]
+    Legacy,
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(debug_alternate)]
+enum Formatted {
+    #[display("{variant} {0}")]
+    Current(u8),
+    #[deprecated]
+    #[display("{variant} {old}")]
+    Legacy { old: u8 },
+}
+
+#[test]
+fn test_deprecated_variants() {
+    assert_eq!(Unit::Current.to_string(), "current");
+    assert_eq!("current".parse(), Ok(Unit::Current));
+    assert_eq!(Unit::display_names(), ["current", "legacy"]);
+    assert_eq!(Formatted::Current(1).to_string(), "Current 1");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_variants_still_display() {
+    assert_eq!(Unit::Legacy.to_string(), "legacy");
+    assert_eq!(Unit::try_from("legacy"), Ok(Unit::Legacy));
+    assert_eq!(Formatted::Legacy { old: 2 }.to_string(), "Legacy 2");
+}
@@ -0,0 +1,71 @@
+// Generated code is held to the 2018 idioms, `Formatter<'_>` rather than a bare `Formatter`
+#![deny(rust_2018_idioms)]
+
+use std::fmt::{Debug, Display};
+
+use enum_display::EnumDisplay;
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum Color {
+    Red,
+    #[display("rgb({0}, {1}, {2})")]
+    Rgb(u8, u8, u8),
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(debug)]
+enum TestEnumWithLifetimeAndGenerics<'a, T: Display> {
+    #[display("{variant}: {name}")]
+    Named {
+        name: &'a str,
+    },
+    #[display("{variant}: {0}")]
+    Value(T),
+    #[display(transparent)]
+    Borrowed(&'a T),
+    Empty,
+}
+
+type Static<T> = TestEnumWithLifetimeAndGenerics<'static, T>;
+type Borrowing<'a> = TestEnumWithLifetimeAndGenerics<'a, f64>;
+
+#[test]
+fn test_boxed_as_dyn_display() {
+    let values: Vec<Box<dyn Display>> = vec![Box::new(Color::Red), Box::new(Color::Rgb(1, 2, 3))];
+    let strings: Vec<String> = values.iter().map(ToString::to_string).collect();
+    assert_eq!(strings, ["Red", "rgb(1, 2, 3)"]);
+}
+
+#[test]
+fn test_static_instantiation_boxed_as_dyn_display() {
+    let values: Vec<Box<dyn Display>> = vec![
+        Box::new(Static::<u32>::Named { name: "label" }),
+        Box::new(Static::<u32>::Value(7)),
+        Box::new(Static::<u32>::Borrowed(&42)),
+        Box::new(Static::<String>::Empty),
+    ];
+    let strings: Vec<String> = values.iter().map(ToString::to_string).collect();
+    assert_eq!(strings, ["Named: label", "Value: 7", "42", "Empty"]);
+}
+
+#[test]
+fn test_borrowing_instantiation_as_dyn_display() {
+    // A trait object can hold a borrow for as long as the data it points at lives
+    let name = String::from("local");
+    let value = 3.5;
+    let values: Vec<Box<dyn Display + '_>> = vec![
+        Box::new(Borrowing::Named { name: &name }),
+        Box::new(Borrowing::Borrowed(&value)),
+    ];
+    // Formatter flags reach the impl through the trait object, and `transparent` passes them on
+    let strings: Vec<String> = values.iter().map(|value| format!("{value:>10}")).collect();
+    assert_eq!(strings, ["Named: local", "       3.5"]);
+}
+
+#[test]
+fn test_dyn_debug_forwards_to_display() {
+    let value: &dyn Debug = &Static::<u8>::Value(1);
+    assert_eq!(format!("{value:?}"), "Value: 1");
+}
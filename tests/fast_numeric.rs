@@ -0,0 +1,65 @@
+// Run with `cargo test --features fast_numeric`
+#![cfg(feature = "fast_numeric")]
+
+use enum_display::EnumDisplay;
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(fast_numeric, prefix = "{", suffix = "}")]
+enum TestEnum {
+    #[display("{variant}({0}, {1})")]
+    Point(i64, u8),
+    #[display("{enum}::{variant} {x}/{y}")]
+    Ratio {
+        x: f32,
+        y: f64,
+    },
+    #[display(fields(count), "{count} items")]
+    Count(usize),
+    // Specs, floats and non-numeric fields fall back to `write!`
+    #[display("{0:>4}")]
+    Padded(u32),
+    #[display("{0} {1}")]
+    Mixed(u32, &'static str),
+    #[display("{{{0}}}")]
+    Braced(i8),
+    Plain,
+}
+
+#[test]
+fn test_fast_numeric_matches_display() {
+    assert_eq!(TestEnum::Point(-5, 255).to_string(), "{Point(-5, 255)}");
+    assert_eq!(
+        TestEnum::Point(i64::MIN, 0).to_string(),
+        format!("{{Point({}, 0)}}", i64::MIN)
+    );
+    assert_eq!(TestEnum::Count(3).to_string(), "{3 items}");
+    assert_eq!(TestEnum::Braced(-1).to_string(), "{{-1}}");
+    assert_eq!(TestEnum::Plain.to_string(), "{Plain}");
+}
+
+#[test]
+fn test_fast_numeric_floats_match_display() {
+    assert_eq!(
+        TestEnum::Ratio { x: 0.5, y: 1.0 }.to_string(),
+        "{TestEnum::Ratio 0.5/1}"
+    );
+    assert_eq!(
+        TestEnum::Ratio { x: 1.5, y: 1e20 }.to_string(),
+        "{TestEnum::Ratio 1.5/100000000000000000000}"
+    );
+    assert_eq!(
+        TestEnum::Ratio {
+            x: f32::NAN,
+            y: f64::NEG_INFINITY
+        }
+        .to_string(),
+        "{TestEnum::Ratio NaN/-inf}"
+    );
+}
+
+#[test]
+fn test_fast_numeric_falls_back() {
+    assert_eq!(TestEnum::Padded(7).to_string(), "{   7}");
+    assert_eq!(TestEnum::Mixed(1, "a").to_string(), "{1 a}");
+}
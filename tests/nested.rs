@@ -0,0 +1,84 @@
+use enum_display::EnumDisplay;
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(case = "Lower")]
+enum Inner {
+    Ready,
+    #[display("waiting {0}s")]
+    Waiting(u32),
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum Outer {
+    #[display("outer: {0}")]
+    Wrapped(Inner),
+    #[display("{variant} [{inner}]")]
+    Named { inner: Inner },
+    #[display(transparent)]
+    Forwarded(Inner),
+}
+
+// Boxed fields of the enum's own type display recursively
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum Expr {
+    #[display("{0}")]
+    Num(i32),
+    #[display("({0} + {1})")]
+    Add(Box<Expr>, Box<Expr>),
+}
+
+// The inner enum only displays when its parameter does, which the outer impl has to require too
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum GenericInner<T> {
+    #[display("value {0}")]
+    Value(T),
+    Nothing,
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum GenericOuter<T> {
+    #[display("outer: {0}")]
+    Wrapped(GenericInner<T>),
+    #[display("{0:?}")]
+    Debugged(T),
+}
+
+#[test]
+fn test_nested_display() {
+    assert_eq!(Outer::Wrapped(Inner::Ready).to_string(), "outer: ready");
+    assert_eq!(
+        Outer::Wrapped(Inner::Waiting(3)).to_string(),
+        "outer: waiting 3s"
+    );
+    assert_eq!(
+        Outer::Named {
+            inner: Inner::Waiting(1)
+        }
+        .to_string(),
+        "Named [waiting 1s]"
+    );
+    assert_eq!(Outer::Forwarded(Inner::Ready).to_string(), "ready");
+}
+
+#[test]
+fn test_recursive_display() {
+    let expr = Expr::Add(
+        Box::new(Expr::Num(1)),
+        Box::new(Expr::Add(Box::new(Expr::Num(2)), Box::new(Expr::Num(3)))),
+    );
+    assert_eq!(expr.to_string(), "(1 + (2 + 3))");
+}
+
+#[test]
+fn test_nested_generic_display() {
+    let wrapped: GenericOuter<u8> = GenericOuter::Wrapped(GenericInner::Value(7));
+    assert_eq!(wrapped.to_string(), "outer: value 7");
+    let nothing: GenericOuter<u8> = GenericOuter::Wrapped(GenericInner::Nothing);
+    assert_eq!(nothing.to_string(), "outer: Nothing");
+    assert_eq!(GenericOuter::Debugged("x").to_string(), "\"x\"");
+}
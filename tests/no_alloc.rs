@@ -0,0 +1,151 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+use enum_display::EnumDisplay;
+
+// Counts allocations made on the current thread so parallel tests don't interfere
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+// A fixed size buffer so writing into it never allocates
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum TestEnum {
+    #[display("{variant} at {x},{y}")]
+    Point {
+        x: i32,
+        y: i32,
+    },
+    #[display("{variant}({0})")]
+    Id(u64),
+    #[display(trim, "  {0}  ")]
+    Trimmed(&'static str),
+    #[display(group_digits(0), "{0}")]
+    Grouped(u64),
+    Empty,
+}
+
+#[test]
+fn test_formatted_named_variant_does_not_allocate() {
+    let value = TestEnum::Point { x: 1, y: -2 };
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| write!(buffer, "{}", value).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "Point at 1,-2");
+}
+
+#[test]
+fn test_formatted_unnamed_variant_does_not_allocate() {
+    let value = TestEnum::Id(42);
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| write!(buffer, "{}", value).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "Id(42)");
+}
+
+#[test]
+fn test_trimmed_variant_does_not_allocate() {
+    let value = TestEnum::Trimmed(" padded ");
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| write!(buffer, "{}", value).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "padded");
+}
+
+#[test]
+fn test_grouped_digits_do_not_allocate() {
+    let value = TestEnum::Grouped(1_234_567);
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| write!(buffer, "{}", value).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "1,234,567");
+}
+
+#[test]
+fn test_unformatted_variant_in_formatted_enum_does_not_allocate() {
+    let value = TestEnum::Empty;
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| write!(buffer, "{}", value).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "Empty");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_unformatted_variant_cow_in_formatted_enum_does_not_allocate() {
+    let mut cow = None;
+    let allocations = allocations_during(|| cow = Some(TestEnum::Empty.display_cow()));
+    assert_eq!(allocations, 0);
+    assert!(matches!(cow, Some(std::borrow::Cow::Borrowed("Empty"))));
+}
+
+#[test]
+fn test_display_into_does_not_allocate() {
+    let value = TestEnum::Point { x: 3, y: 4 };
+    let mut buffer = Buffer::new();
+    let allocations = allocations_during(|| value.display_into(&mut buffer).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(buffer.as_str(), "Point at 3,4");
+
+    // A full buffer is reported as an error instead of being written past
+    let mut buffer = Buffer::new();
+    let long = TestEnum::Id(u64::MAX);
+    for _ in 0..2 {
+        long.display_into(&mut buffer).unwrap();
+    }
+    assert!(long.display_into(&mut buffer).is_err());
+}
@@ -0,0 +1,84 @@
+// The derived code only relies on `core`, so it builds in a `no_std` crate that never mentions
+// `alloc` or `String`.
+#![no_std]
+
+use core::fmt::{self, Write};
+
+use enum_display::EnumDisplay;
+
+// A fixed size buffer to format into, there's no `String` to reach for here
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn display(value: impl fmt::Display) -> Buffer {
+    let mut buffer = Buffer::new();
+    write!(buffer, "{}", value).unwrap();
+    buffer
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(case = "Kebab", pad)]
+enum TestEnum {
+    #[display("{variant} at {x},{y}")]
+    Point {
+        x: i32,
+        y: i32,
+    },
+    #[display("{enum}::{variant}({0:>3})")]
+    Id(u64),
+    #[display(transparent)]
+    Inner(u8),
+    EmptyValue,
+}
+
+#[test]
+fn test_formatted_variants() {
+    assert_eq!(
+        display(TestEnum::Point { x: 1, y: -2 }).as_str(),
+        "point at 1,-2"
+    );
+    assert_eq!(display(TestEnum::Id(7)).as_str(), "TestEnum::id(  7)");
+    assert_eq!(display(TestEnum::Inner(9)).as_str(), "9");
+}
+
+#[test]
+fn test_unformatted_variant() {
+    assert_eq!(display(TestEnum::EmptyValue).as_str(), "empty-value");
+    assert_eq!(TestEnum::EmptyValue.variant_name(), "empty-value");
+}
+
+#[test]
+fn test_display_into() {
+    let mut buffer = Buffer::new();
+    TestEnum::Id(7).display_into(&mut buffer).unwrap();
+    TestEnum::EmptyValue.display_into(&mut buffer).unwrap();
+    assert_eq!(buffer.as_str(), "TestEnum::id(  7)empty-value");
+}
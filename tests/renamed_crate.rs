@@ -0,0 +1,28 @@
+// Stands in for a dependency renamed in Cargo.toml, or a facade crate re-exporting this one
+extern crate enum_display as enum_display_renamed;
+
+use enum_display_renamed::{EnumDisplay, EnumFromStr, ParseEnumError};
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumDisplay, EnumFromStr)]
+#[enum_display(crate = "enum_display_renamed", case = "Kebab")]
+enum TestEnum {
+    LightRed,
+    DarkGreen,
+}
+
+#[test]
+fn test_display_with_renamed_crate() {
+    assert_eq!(TestEnum::LightRed.to_string(), "light-red");
+    #[cfg(feature = "alloc")]
+    assert_eq!(TestEnum::DarkGreen.display_cow(), "dark-green");
+}
+
+#[test]
+fn test_from_str_with_renamed_crate() {
+    assert_eq!("dark-green".parse::<TestEnum>(), Ok(TestEnum::DarkGreen));
+    let error: ParseEnumError = "blue".parse::<TestEnum>().unwrap_err();
+    assert_eq!(error, ParseEnumError::new("blue"));
+    #[cfg(feature = "alloc")]
+    assert_eq!(error.input(), "blue");
+}
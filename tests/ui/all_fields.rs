@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Tuple {
+    #[display(all_fields)]
+    Point(i32, i32),
+}
+
+#[derive(EnumDisplay)]
+enum Formatted {
+    #[display(all_fields, "{x}")]
+    Point { x: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(ascii_only)]
+enum Format {
+    #[display("{variant} → {0}")]
+    Arrow(u8),
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(ascii_only, prefix = "«", suffix = "»")]
+enum Prefixed {
+    Quoted,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(ascii_only)]
+enum Name {
+    Café,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(ascii_only)]
+enum Join {
+    #[display(join = " · ")]
+    Pair(u8, u8),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(case = "Snake", boundaries = "lowerupper")]
+enum Words {
+    HttpServer,
+}
+
+fn main() {}
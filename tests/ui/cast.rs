@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Point {
+    #[display(cast = "f64", "{x},{y}")]
+    Named { x: i32, y: i32 },
+}
+
+#[derive(EnumDisplay)]
+enum Level {
+    #[display(cast = "u64")]
+    Unformatted(u8),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Twice {
+    #[display("a")]
+    #[display("b")]
+    Ab,
+}
+
+#[derive(EnumDisplay)]
+enum SameAttribute {
+    #[display("a", format = "b")]
+    Ab,
+}
+
+#[derive(EnumDisplay)]
+enum Transparent {
+    #[display(transparent)]
+    #[display("{0}")]
+    Inner(u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Conflicting {
+    #[display(debug_fields, "{0}")]
+    Value(i32),
+}
+
+fn main() {}
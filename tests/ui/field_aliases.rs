@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Location {
+    #[display(fields("lat"), "{lat}")]
+    Coordinates(f64, f64),
+}
+
+#[derive(EnumDisplay)]
+enum Named {
+    #[display(fields("lat"), "{lat}")]
+    Coordinates { lat: f64 },
+}
+
+fn main() {}
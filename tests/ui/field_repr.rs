@@ -0,0 +1,16 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(field_repr = "Debug")]
+enum Shape {
+    #[display("{0}")]
+    Circle(u32),
+}
+
+#[derive(EnumDisplay)]
+enum Size {
+    #[display(field_repr = "hex", "{0}")]
+    Bytes(u32),
+}
+
+fn main() {}
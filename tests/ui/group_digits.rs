@@ -0,0 +1,27 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Unformatted {
+    #[display(group_digits(0))]
+    Count(u64),
+}
+
+#[derive(EnumDisplay)]
+enum Missing {
+    #[display(group_digits(total), "{count}")]
+    Named { count: u64 },
+}
+
+#[derive(EnumDisplay)]
+enum OutOfRange {
+    #[display(group_digits(1), "{0}")]
+    Tuple(u64),
+}
+
+#[derive(EnumDisplay)]
+enum NotAnInteger {
+    #[display(group_digits(0), "{0}")]
+    Ratio(f64),
+}
+
+fn main() {}
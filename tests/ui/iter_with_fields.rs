@@ -0,0 +1,12 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Shape {
+    Empty,
+    #[display("circle of {0}")]
+    Circle(u32),
+}
+
+fn main() {
+    let _ = Shape::iter();
+}
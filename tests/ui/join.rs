@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Named {
+    #[display(join = ", ")]
+    Point { x: i32, y: i32 },
+}
+
+#[derive(EnumDisplay)]
+enum WithFormat {
+    #[display("{0}", join = ", ")]
+    Pair(i32, i32),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Named {
+    #[display("{last}")]
+    Point { x: i32, y: i32 },
+}
+
+#[derive(EnumDisplay)]
+enum Empty {
+    #[display("{last}")]
+    Nothing(),
+}
+
+fn main() {}
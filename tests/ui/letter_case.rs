@@ -0,0 +1,9 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Both {
+    #[display(uppercase, lowercase)]
+    Shouting,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Unclosed {
+    #[display("{0")]
+    Value(u32),
+}
+
+#[derive(EnumDisplay)]
+enum Stray {
+    #[display("{variant}}")]
+    Name,
+}
+
+#[derive(EnumDisplay)]
+enum Invalid {
+    #[display("{variant name}")]
+    Name,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_display::{EnumDisplay, EnumFromStr};
+
+#[derive(EnumDisplay, EnumFromStr)]
+enum Mode {
+    #[display(default)]
+    Fast,
+    #[display(default)]
+    Slow,
+}
+
+fn main() {}
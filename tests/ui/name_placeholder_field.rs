@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Variant {
+    #[display("{variant}")]
+    A { variant: String },
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(format = "{enum}: {variant}")]
+enum Enum {
+    B { r#enum: u8 },
+}
+
+fn main() {}
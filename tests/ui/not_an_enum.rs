@@ -0,0 +1,17 @@
+use enum_display::{EnumDisplay, EnumFromStr, EnumTryFrom};
+
+#[derive(EnumDisplay)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+#[derive(EnumFromStr)]
+struct Name;
+
+#[derive(EnumTryFrom)]
+struct Other {
+    name: &'static str,
+}
+
+fn main() {}
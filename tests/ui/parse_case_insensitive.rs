@@ -0,0 +1,11 @@
+use enum_display::{EnumDisplay, EnumFromStr};
+
+#[derive(EnumDisplay, EnumFromStr)]
+#[enum_display(parse_case_insensitive)]
+enum Protocol {
+    Http,
+    #[display("HTTP")]
+    LegacyHttp,
+}
+
+fn main() {}
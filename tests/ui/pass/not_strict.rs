@@ -0,0 +1,34 @@
+// Without `strict`, repeated arguments keep letting the last one win and attributes on fields are
+// ignored, as they always have been
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(case = "Snake", case = "Kebab")]
+enum RepeatedCase {
+    LightRed,
+}
+
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+enum FieldAttribute {
+    #[display("{variant} {value}")]
+    Named {
+        #[display("{value:>4}")]
+        value: u32,
+    },
+}
+
+// Lists that add to each other can be repeated under `strict` too
+#[allow(dead_code)]
+#[derive(EnumDisplay)]
+#[enum_display(strict)]
+enum RepeatedBindings {
+    #[display(bind(sum = "x + y"), bind(product = "x * y"), "{sum} {product}")]
+    Pair { x: i32, y: i32 },
+}
+
+fn main() {
+    assert_eq!(RepeatedCase::LightRed.to_string(), "light-red");
+    assert_eq!(FieldAttribute::Named { value: 1 }.to_string(), "Named 1");
+    assert_eq!(RepeatedBindings::Pair { x: 2, y: 3 }.to_string(), "5 6");
+}
@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Named {
+    #[display("{0} {2}")]
+    Point { x: i32, y: i32 },
+}
+
+#[derive(EnumDisplay)]
+enum Unnamed {
+    #[display("{1}")]
+    Single(i32),
+}
+
+fn main() {}
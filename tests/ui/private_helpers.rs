@@ -0,0 +1,28 @@
+use enum_display::EnumDisplay;
+
+mod shapes {
+    use enum_display::EnumDisplay;
+
+    #[derive(EnumDisplay)]
+    enum Shape {
+        Circle,
+    }
+
+    // The type escapes its module, but its helpers stay as private as it is
+    #[allow(private_interfaces)]
+    pub fn circle() -> Shape {
+        Shape::Circle
+    }
+}
+
+#[derive(EnumDisplay)]
+pub enum Public {
+    Value,
+}
+
+fn main() {
+    // Display itself is a trait impl, so it's available wherever the value is
+    let _ = shapes::circle().to_string();
+    let _ = Public::Value.variant_name();
+    let _ = shapes::circle().variant_name();
+}
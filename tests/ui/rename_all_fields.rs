@@ -0,0 +1,10 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(rename_all_fields = "Kebab")]
+enum Contact {
+    #[display("{first_name}")]
+    Person { first_name: String },
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(strict, case = "Snake", case = "Kebab")]
+enum RepeatedCase {
+    LightRed,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(strict)]
+enum RepeatedFlag {
+    #[display(quote)]
+    #[display(quote, "{variant}")]
+    Name,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(strict)]
+enum FieldAttribute {
+    #[display("{variant} {value}")]
+    Named {
+        #[display("{value:>4}")]
+        value: u32,
+    },
+}
+
+// `strict` can come after the arguments it checks
+#[derive(EnumDisplay)]
+#[enum_display(prefix = "<", prefix = "[", strict)]
+enum StrictLast {
+    Name,
+}
+
+fn main() {}
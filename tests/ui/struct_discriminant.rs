@@ -0,0 +1,7 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[display("{discriminant}")]
+struct Unit;
+
+fn main() {}
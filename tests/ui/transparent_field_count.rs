@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Unit {
+    #[display(transparent)]
+    Empty,
+}
+
+#[derive(EnumDisplay)]
+enum Pair {
+    #[display(transparent)]
+    Both(u32, u32),
+}
+
+fn main() {}
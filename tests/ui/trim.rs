@@ -0,0 +1,15 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Label {
+    #[display(trim)]
+    Unformatted,
+}
+
+#[derive(EnumDisplay)]
+enum Wrapper {
+    #[display(trim, transparent)]
+    Inner(String),
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Empty {
+    #[display(truncate = 0)]
+    Nothing,
+}
+
+#[derive(EnumDisplay)]
+enum Quoted {
+    #[display(truncate = "20")]
+    Text,
+}
+
+fn write_point(f: &mut std::fmt::Formatter, x: &i32) -> std::fmt::Result {
+    write!(f, "{x}")
+}
+
+#[derive(EnumDisplay)]
+enum Custom {
+    #[display(truncate = 4, with = "write_point")]
+    Point(i32),
+}
+
+fn main() {}
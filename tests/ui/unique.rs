@@ -0,0 +1,21 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(unique, case = "Snake")]
+enum Header {
+    HttpRequest,
+    HTTPRequest,
+    #[display("http_request")]
+    Literal,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(unique)]
+enum Status {
+    #[display("ok")]
+    Success,
+    #[display("ok")]
+    Done,
+}
+
+fn main() {}
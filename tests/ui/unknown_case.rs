@@ -0,0 +1,29 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(case = "Kabab")]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(EnumDisplay)]
+enum Shape {
+    Circle,
+    #[display(case = "Snek")]
+    Square,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(case = Kabab)]
+enum Unquoted {
+    Red,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(case = Case::Kabab)]
+enum Path {
+    Red,
+}
+
+fn main() {}
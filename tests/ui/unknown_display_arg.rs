@@ -0,0 +1,21 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Typo {
+    #[display(fmt = "{variant}!")]
+    Name,
+}
+
+#[derive(EnumDisplay)]
+enum Flag {
+    #[display(transparnet)]
+    Inner(u32),
+}
+
+#[derive(EnumDisplay)]
+enum NotAString {
+    #[display(format = 42)]
+    Name,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(case = "Snake", casing = "Kebab")]
+enum Misspelled {
+    LightRed,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display("Snake")]
+enum Positional {
+    LightRed,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(prefix = 1)]
+enum NotAString {
+    LightRed,
+}
+
+fn main() {}
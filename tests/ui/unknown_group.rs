@@ -0,0 +1,17 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+#[enum_display(group(errors = "an error occurred"))]
+enum Typo {
+    #[display(group = "erors")]
+    NotFound,
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(group(errors = "an error occurred"))]
+enum WithFormat {
+    #[display(group = "errors", "{variant}")]
+    NotFound,
+}
+
+fn main() {}
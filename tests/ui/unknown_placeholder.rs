@@ -0,0 +1,36 @@
+use enum_display::EnumDisplay;
+
+#[derive(EnumDisplay)]
+enum Person {
+    #[display("{variant}: {naem}")]
+    Named { name: String },
+}
+
+#[derive(EnumDisplay)]
+enum Point {
+    #[display(fields(x, y), "({x}, {z})")]
+    Coords(i32, i32),
+}
+
+#[derive(EnumDisplay)]
+enum Padded {
+    #[display("{0:>width$}")]
+    Value(u32),
+}
+
+#[derive(EnumDisplay)]
+#[enum_display(format = "{variant} {id}")]
+enum Default {
+    WithId { id: u32 },
+    WithoutId,
+}
+
+const VERSION: u32 = 1;
+
+#[derive(EnumDisplay)]
+enum Unlisted {
+    #[display("{variant} v{VERSION}")]
+    Release,
+}
+
+fn main() {}
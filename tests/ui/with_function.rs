@@ -0,0 +1,19 @@
+use enum_display::EnumDisplay;
+
+fn fmt_value(f: &mut std::fmt::Formatter, value: &i32) -> std::fmt::Result {
+    write!(f, "{}", value)
+}
+
+#[derive(EnumDisplay)]
+enum Formatted {
+    #[display("{0}", with = "fmt_value")]
+    Value(i32),
+}
+
+#[derive(EnumDisplay)]
+enum Quoted {
+    #[display(quote, with = "fmt_value")]
+    Value(i32),
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// The inherent helpers are as visible as the enum, so they're callable wherever it can be named
+mod shapes {
+    use enum_display::EnumDisplay;
+
+    #[allow(dead_code)]
+    #[derive(EnumDisplay)]
+    #[enum_display(case = "Lower")]
+    pub(crate) enum Shape {
+        Circle,
+        Square,
+    }
+
+    pub mod nested {
+        use enum_display::EnumDisplay;
+
+        #[allow(dead_code)]
+        #[derive(EnumDisplay)]
+        pub(super) enum Corner {
+            TopLeft,
+            BottomRight,
+        }
+    }
+
+    pub fn corner_name() -> &'static str {
+        nested::Corner::BottomRight.variant_name()
+    }
+}
+
+#[test]
+fn test_pub_crate_helpers_from_parent_module() {
+    use shapes::Shape;
+
+    assert_eq!(Shape::Square.variant_name(), "square");
+    assert_eq!(Shape::display_names(), ["circle", "square"]);
+    assert_eq!(Shape::Circle.variant_index(), 0);
+}
+
+#[test]
+fn test_pub_super_helpers_from_parent_module() {
+    assert_eq!(shapes::corner_name(), "BottomRight");
+}